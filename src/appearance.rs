@@ -1,6 +1,15 @@
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock, RwLock};
 
+use fxhash::FxHashMap;
+use iced::Color;
+use iced::theme::Palette;
 use iced_material::Theme;
+use serde::Deserialize;
+
+use crate::values::Value;
+
+static SYNTAX_THEMES: LazyLock<RwLock<FxHashMap<String, SyntaxTheme>>> =
+    LazyLock::new(|| RwLock::new(FxHashMap::default()));
 
 pub fn iced_theme_from_str(theme_name: &str) -> iced::Theme {
     match theme_name {
@@ -30,6 +39,140 @@ pub fn iced_theme_from_str(theme_name: &str) -> iced::Theme {
     }
 }
 
+/// Optional per-theme overrides for the colors used by the code view's
+/// syntax highlighter, read from the `[syntax]` table of a theme file.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SyntaxColors {
+    pub text: Option<Color>,
+    pub primary: Option<Color>,
+    pub success: Option<Color>,
+    pub warning: Option<Color>,
+    pub danger: Option<Color>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SyntaxColorsRepr {
+    text: Option<String>,
+    primary: Option<String>,
+    success: Option<String>,
+    warning: Option<String>,
+    danger: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ThemeFileRepr {
+    #[serde(default)]
+    syntax: Option<SyntaxColorsRepr>,
+}
+
+impl SyntaxColors {
+    /// Reads the `[syntax]` table of a theme file, if there is one.
+    ///
+    /// Entries that aren't valid `#rrggbb` or `#rrggbbaa` colors are ignored.
+    pub fn from_theme_file(content: &str) -> Option<Self> {
+        let ThemeFileRepr { syntax } = toml::from_str(content).ok()?;
+        let syntax = syntax?;
+
+        let parse = |value: Option<String>| {
+            value.and_then(|value| <Color as Value>::from_str(&value).ok())
+        };
+
+        Some(Self {
+            text: parse(syntax.text),
+            primary: parse(syntax.primary),
+            success: parse(syntax.success),
+            warning: parse(syntax.warning),
+            danger: parse(syntax.danger),
+        })
+    }
+
+    fn apply(self, palette: Palette) -> Palette {
+        Palette {
+            background: palette.background,
+            text: self.text.unwrap_or(palette.text),
+            primary: self.primary.unwrap_or(palette.primary),
+            success: self.success.unwrap_or(palette.success),
+            warning: self.warning.unwrap_or(palette.warning),
+            danger: self.danger.unwrap_or(palette.danger),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SyntaxTheme {
+    colors: Option<SyntaxColors>,
+    theme: Option<iced::Theme>,
+}
+
+/// Registers the syntax color overrides of the theme called `theme_name`.
+pub fn register_syntax_colors(theme_name: &str, colors: SyntaxColors) {
+    if let Ok(mut themes) = SYNTAX_THEMES.write() {
+        let _ = themes.insert(
+            theme_name.to_owned(),
+            SyntaxTheme {
+                colors: Some(colors),
+                theme: None,
+            },
+        );
+    }
+}
+
+/// Forgets the syntax colors of every theme, along with the highlighter
+/// themes built from them, before the theme files are read again.
+pub fn clear_syntax_colors() {
+    if let Ok(mut themes) = SYNTAX_THEMES.write() {
+        themes.clear();
+    }
+}
+
+/// Derives the highlighter palette from the colors of a Material theme.
+pub fn syntax_palette(theme: &Theme) -> Palette {
+    let colors = theme.colors();
+
+    Palette {
+        background: colors.surface.color,
+        text: colors.surface.on_surface,
+        primary: colors.primary.color,
+        success: colors.tertiary.color,
+        warning: colors.secondary.color,
+        danger: colors.error.color,
+    }
+}
+
+/// Returns the [`iced::Theme`] the code view highlights with when `theme` is
+/// active, taking the theme's own syntax colors into account.
+pub fn syntax_theme(theme: &Theme) -> iced::Theme {
+    let name = theme.name();
+
+    if let Ok(themes) = SYNTAX_THEMES.read()
+        && let Some(SyntaxTheme {
+            theme: Some(theme), ..
+        }) = themes.get(name)
+    {
+        return theme.clone();
+    }
+
+    let Ok(mut themes) = SYNTAX_THEMES.write() else {
+        return iced::Theme::custom(name.to_owned(), syntax_palette(theme));
+    };
+
+    let entry = themes.entry(name.to_owned()).or_insert(SyntaxTheme {
+        colors: None,
+        theme: None,
+    });
+
+    let palette = syntax_palette(theme);
+    let palette = match entry.colors {
+        Some(colors) => colors.apply(palette),
+        None => palette,
+    };
+
+    entry
+        .theme
+        .get_or_insert_with(|| iced::Theme::custom(name.to_owned(), palette))
+        .clone()
+}
+
 #[derive(Debug, Clone)]
 pub struct Appearance {
     pub selected: Theme,
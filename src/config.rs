@@ -3,26 +3,58 @@
 
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use iced_material::Theme;
 use serde::{Deserialize, Serialize};
 use tokio_stream::StreamExt;
 use tokio_stream::wrappers::ReadDirStream;
 
-use crate::appearance::Appearance;
+use crate::appearance::{
+    Appearance, SyntaxColors, clear_syntax_colors, register_syntax_colors,
+};
 use crate::{Error, environment};
 
-#[derive(Debug, Clone, Default)]
+/// How often unsaved changes are autosaved by default, in seconds.
+pub const DEFAULT_AUTOSAVE_INTERVAL: u64 = 60;
+/// How many recently opened projects are remembered.
+pub const MAX_RECENT_PROJECTS: usize = 10;
+
+#[derive(Debug, Clone)]
 pub struct Config {
     pub appearance: Appearance,
-    pub last_project: Option<PathBuf>,
+    /// Recently opened projects, most recent first.
+    pub recent_projects: Vec<PathBuf>,
+    /// Seconds between autosaves, `0` disables autosaving.
+    pub autosave_interval: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            appearance: Appearance::default(),
+            recent_projects: Vec::new(),
+            autosave_interval: DEFAULT_AUTOSAVE_INTERVAL,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ConfigRepr {
     #[serde(default)]
     pub theme: String,
+    /// Only read, so configs from before the recent projects list keep
+    /// their last project.
+    #[serde(default, skip_serializing)]
     pub last_project: Option<PathBuf>,
+    #[serde(default)]
+    pub recent_projects: Vec<PathBuf>,
+    #[serde(default = "default_autosave_interval")]
+    pub autosave_interval: u64,
+}
+
+fn default_autosave_interval() -> u64 {
+    DEFAULT_AUTOSAVE_INTERVAL
 }
 
 impl Config {
@@ -35,7 +67,22 @@ impl Config {
     }
 
     pub fn last_project(&self) -> Option<&Path> {
-        self.last_project.as_deref()
+        self.recent_projects.first().map(PathBuf::as_path)
+    }
+
+    pub fn recent_projects(&self) -> &[PathBuf] {
+        &self.recent_projects
+    }
+
+    /// Moves `path` to the top of the recent projects list.
+    pub fn add_recent_project(&mut self, path: &Path) {
+        self.recent_projects.retain(|recent| recent != path);
+        self.recent_projects.insert(0, path.to_path_buf());
+        self.recent_projects.truncate(MAX_RECENT_PROJECTS);
+    }
+
+    pub fn remove_recent_project(&mut self, path: &Path) {
+        self.recent_projects.retain(|recent| recent != path);
     }
 
     pub fn config_dir() -> PathBuf {
@@ -58,10 +105,29 @@ impl Config {
         dir
     }
 
+    pub fn templates_dir() -> PathBuf {
+        let dir = Self::config_dir().join("templates");
+
+        if !dir.exists() {
+            std::fs::create_dir_all(dir.as_path())
+                .expect("expected permissions to create templates folder");
+        }
+        dir
+    }
+
     pub fn config_file_path() -> PathBuf {
         Self::config_dir().join(environment::CONFIG_FILE_NAME)
     }
 
+    pub fn autosave_file_path() -> PathBuf {
+        Self::config_dir().join(environment::AUTOSAVE_FILE_NAME)
+    }
+
+    pub fn autosave_interval(&self) -> Option<Duration> {
+        (self.autosave_interval > 0)
+            .then(|| Duration::from_secs(self.autosave_interval))
+    }
+
     pub async fn load() -> Result<Self, Error> {
         use tokio::fs;
 
@@ -80,14 +146,23 @@ impl Config {
         let ConfigRepr {
             theme,
             last_project,
+            mut recent_projects,
+            autosave_interval,
         } = toml::from_str(content.as_ref())?;
 
+        if recent_projects.is_empty() {
+            recent_projects.extend(last_project);
+        }
+        recent_projects.retain(|path| path.is_file());
+        recent_projects.truncate(MAX_RECENT_PROJECTS);
+
         let appearance =
             Self::load_appearance(&theme).await.unwrap_or_default();
 
         Ok(Self {
             appearance,
-            last_project,
+            recent_projects,
+            autosave_interval,
         })
     }
 
@@ -96,11 +171,18 @@ impl Config {
     ) -> Result<Appearance, Error> {
         use tokio::fs;
 
+        // Themes whose files changed or are gone keep no stale colors
+        clear_syntax_colors();
+
         let read_entry = async move |entry: fs::DirEntry| {
             let content = fs::read_to_string(entry.path()).await.ok()?;
 
             let theme: Theme = toml::from_str(content.as_ref()).ok()?;
 
+            if let Some(colors) = SyntaxColors::from_theme_file(&content) {
+                register_syntax_colors(theme.name(), colors);
+            }
+
             Some(theme)
         };
 
@@ -142,7 +224,9 @@ impl Config {
 
         let config = ConfigRepr {
             theme: self.appearance.selected.to_string(),
-            last_project: self.last_project,
+            last_project: None,
+            recent_projects: self.recent_projects,
+            autosave_interval: self.autosave_interval,
         };
 
         file.write_all(toml::to_string_pretty(&config)?.as_bytes())
@@ -11,6 +11,9 @@ use crate::widget::button::danger;
 pub const UNSAVED_CHANGES_TITLE: &str = "Hold on for a sec!";
 pub const WARNING_TITLE: &str = "Heads up!";
 pub const ERROR_TITLE: &str = "Oops! Something went wrong.";
+pub const RECOVER_TITLE: &str = "Welcome back!";
+pub const EXTERNAL_CHANGE_TITLE: &str = "The project file changed!";
+pub const PROJECT_SETTINGS_TITLE: &str = "Project settings";
 
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub enum Action {
@@ -18,12 +21,18 @@ pub enum Action {
     None,
     Close,
     UnsavedChanges(UnsavedChanges),
+    Recover,
+    ExternalChange,
+    RemoveView,
+    /// Shows the project settings form instead of text.
+    ProjectSettings,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum UnsavedChanges {
-    New,
-    Open,
+    /// Closing the active document.
+    Close,
+    /// Closing iced Builder, asked for every document with changes.
     Exit,
 }
 
@@ -40,6 +49,30 @@ impl From<Action> for Vec<Element<'_, Message>> {
                     .into(),
                 button("Cancel", Message::DialogCancel).into(),
             ],
+            Action::ExternalChange => vec![
+                button("Keep Mine", Message::DialogNo).style(danger).into(),
+                Space::with_width(20).into(),
+                button("Reload", Message::DialogYes)
+                    .style(filled_tonal)
+                    .into(),
+                button("Show Diff", Message::ShowExternalDiff).into(),
+            ],
+            Action::RemoveView => vec![
+                button("Remove", Message::DialogYes).style(danger).into(),
+                button("Cancel", Message::DialogCancel).into(),
+            ],
+            Action::ProjectSettings => vec![
+                button("Apply", Message::DialogYes)
+                    .style(filled_tonal)
+                    .into(),
+                button("Cancel", Message::DialogCancel).into(),
+            ],
+            Action::Recover => vec![
+                button("Discard", Message::DialogNo).style(danger).into(),
+                button("Recover", Message::DialogYes)
+                    .style(filled_tonal)
+                    .into(),
+            ],
         }
     }
 }
@@ -85,10 +118,34 @@ impl Dialog {
         )
     }
 
+    pub fn recover() -> Self {
+        Self::new(
+            RECOVER_TITLE,
+            "iced Builder didn't shut down properly last time and some of your work wasn't saved. Do you want to recover it?",
+            Action::Recover,
+        )
+    }
+
+    pub fn external_change(content: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(EXTERNAL_CHANGE_TITLE, content, Action::ExternalChange)
+    }
+
+    pub fn remove_view(content: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(WARNING_TITLE, content, Action::RemoveView)
+    }
+
+    pub fn project_settings() -> Self {
+        Self::new(PROJECT_SETTINGS_TITLE, "", Action::ProjectSettings)
+    }
+
     pub fn close(&mut self) {
         self.is_open = false;
     }
 
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
     pub fn action(&self) -> Action {
         self.action
     }
@@ -96,11 +153,20 @@ impl Dialog {
     pub fn as_iced_dialog<'a>(
         &'a self,
         base: impl Into<Element<'a, Message>>,
+    ) -> iced_dialog::Dialog<'a, Message, iced_material::Theme> {
+        self.as_iced_dialog_with(base, text(&*self.content))
+    }
+
+    /// Shows `content` in the dialog instead of its text.
+    pub fn as_iced_dialog_with<'a>(
+        &'a self,
+        base: impl Into<Element<'a, Message>>,
+        content: impl Into<Element<'a, Message>>,
     ) -> iced_dialog::Dialog<'a, Message, iced_material::Theme> {
         iced_dialog::Dialog::with_buttons(
             self.is_open,
             base,
-            text(&*self.content),
+            content,
             self.action.into(),
         )
         .title(&*self.title)
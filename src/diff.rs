@@ -0,0 +1,181 @@
+//! A small line-based diff, good enough to show how two versions of a project
+//! file differ.
+
+/// Unchanged lines shown around every change.
+const CONTEXT: usize = 2;
+/// Above this many cells the LCS table gets too big, so we give up on finding
+/// common lines and show the whole section as replaced.
+const MAX_TABLE_SIZE: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+impl Line<'_> {
+    fn is_change(&self) -> bool {
+        !matches!(self, Self::Same(_))
+    }
+}
+
+/// Computes the line differences between `old` and `new`.
+pub fn lines<'a>(old: &'a str, new: &'a str) -> Vec<Line<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut result: Vec<Line<'a>> =
+        old[..prefix].iter().map(|line| Line::Same(line)).collect();
+    result.extend(middle(old_middle, new_middle));
+    result.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| Line::Same(line)),
+    );
+
+    result
+}
+
+fn middle<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Line<'a>> {
+    if (old.len() + 1) * (new.len() + 1) > MAX_TABLE_SIZE {
+        return old
+            .iter()
+            .map(|line| Line::Removed(line))
+            .chain(new.iter().map(|line| Line::Added(line)))
+            .collect();
+    }
+
+    // table[i][j] is the length of the longest common subsequence of
+    // old[i..] and new[j..]
+    let width = new.len() + 1;
+    let mut table = vec![0_u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            table[i * width + j] = if old[i] == new[j] {
+                table[(i + 1) * width + j + 1] + 1
+            } else {
+                table[(i + 1) * width + j].max(table[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(old.len() + new.len());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            result.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if table[(i + 1) * width + j] >= table[i * width + j + 1] {
+            result.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            result.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+    result.extend(old[i..].iter().map(|line| Line::Removed(line)));
+    result.extend(new[j..].iter().map(|line| Line::Added(line)));
+
+    result
+}
+
+/// Renders the changes between `old` and `new` with a bit of context,
+/// showing at most `max_lines` lines.
+pub fn render(old: &str, new: &str, max_lines: usize) -> String {
+    let lines = lines(old, new);
+
+    let visible: Vec<bool> = (0..lines.len())
+        .map(|index| {
+            let start = index.saturating_sub(CONTEXT);
+            let end = (index + CONTEXT + 1).min(lines.len());
+
+            lines[start..end].iter().any(Line::is_change)
+        })
+        .collect();
+
+    let mut output: Vec<String> = Vec::new();
+    let mut skipped = false;
+    for (line, visible) in lines.iter().zip(visible) {
+        if !visible {
+            skipped = true;
+            continue;
+        }
+
+        if skipped && !output.is_empty() {
+            output.push("  …".to_owned());
+        }
+        skipped = false;
+
+        output.push(match line {
+            Line::Same(line) => format!("  {line}"),
+            Line::Removed(line) => format!("- {line}"),
+            Line::Added(line) => format!("+ {line}"),
+        });
+    }
+
+    if output.len() > max_lines {
+        let hidden = output.len() - max_lines;
+        output.truncate(max_lines);
+        output.push(format!("  … and {hidden} more lines"));
+    }
+
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_changed_line() {
+        assert_eq!(
+            lines("a\nb\nc", "a\nx\nc"),
+            vec![
+                Line::Same("a"),
+                Line::Removed("b"),
+                Line::Added("x"),
+                Line::Same("c"),
+            ]
+        )
+    }
+
+    #[test]
+    fn finds_insertions_and_removals() {
+        assert_eq!(
+            lines("a\nb\nc\nd", "a\nc\nd\ne"),
+            vec![
+                Line::Same("a"),
+                Line::Removed("b"),
+                Line::Same("c"),
+                Line::Same("d"),
+                Line::Added("e"),
+            ]
+        )
+    }
+
+    #[test]
+    fn renders_only_context_around_changes() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9";
+        let new = "1\n2\n3\n4\n5\n6\n7\n8\nnine";
+
+        assert_eq!(render(old, new, 10), "  7\n  8\n- 9\n+ nine")
+    }
+
+    #[test]
+    fn renders_nothing_for_equal_text() {
+        assert_eq!(render("a\nb", "a\nb", 10), "")
+    }
+}
@@ -0,0 +1,343 @@
+//! Projects open in tabs, each with its own file and undo history.
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{self, AtomicU64};
+
+use iced::Alignment;
+use iced::advanced::widget::Id;
+use iced::widget::{Row, button, row, text};
+
+use crate::lint::{self, Problem};
+use crate::types::asset;
+use crate::types::{Element, Message, Project};
+
+/// Edits that can be undone per document.
+const MAX_HISTORY: usize = 100;
+
+/// Identifies a document across tab changes, for results of tasks started
+/// while it was active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Key(u64);
+
+impl Key {
+    fn unique() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+
+        Self(NEXT.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+/// A value changed by a run of edits which are undone at once, like typing
+/// in a text input or dragging a slider.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Field {
+    ViewName(usize),
+    Override(Id, String),
+    /// The text of a segment of a rich text.
+    SpanText(Id, usize),
+    FontFamily(Id),
+    Description(Id),
+    /// Any slider, until it's released.
+    Slider,
+}
+
+#[derive(Debug)]
+pub struct Document {
+    key: Key,
+    pub project: Project,
+    pub path: Option<PathBuf>,
+    /// Hash of the project file as we last read or wrote it.
+    pub file_hash: Option<u64>,
+    pub is_dirty: bool,
+    /// The element shown in the inspector.
+    pub selected: Option<Id>,
+    /// The accessibility problems of the project, as of its last change.
+    pub problems: Vec<Problem>,
+    /// The assets whose files couldn't be found, as of the last time the
+    /// assets or the project file changed.
+    pub missing_assets: Vec<PathBuf>,
+    undo: Vec<Project>,
+    redo: Vec<Project>,
+    /// The field changed by the last edit, which changing again continues.
+    editing: Option<Field>,
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self::new(Project::new(), None)
+    }
+}
+
+impl Document {
+    pub fn new(project: Project, path: Option<PathBuf>) -> Self {
+        Self {
+            key: Key::unique(),
+            problems: lint::check_project(&project),
+            missing_assets: missing_assets(&project, path.as_deref()),
+            project,
+            path,
+            file_hash: None,
+            is_dirty: false,
+            selected: None,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            editing: None,
+        }
+    }
+
+    pub fn key(&self) -> Key {
+        self.key
+    }
+
+    /// The name shown in the document's tab.
+    pub fn name(&self) -> String {
+        match (&self.path, &self.project.title) {
+            (Some(path), _) => path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            (None, Some(title)) => title.clone(),
+            (None, None) => String::from("Untitled"),
+        }
+    }
+
+    /// Checks the project again after a change.
+    pub fn refresh_problems(&mut self) {
+        self.problems = lint::check_project(&self.project);
+    }
+
+    /// Looks for the files of the assets again after they changed.
+    pub fn refresh_assets(&mut self) {
+        self.missing_assets =
+            missing_assets(&self.project, self.path.as_deref());
+    }
+
+    /// Whether the document is an untouched new project, which can be
+    /// replaced by the next opened one.
+    pub fn is_pristine(&self) -> bool {
+        !self.is_dirty
+            && self.path.is_none()
+            && self.project.is_blank()
+            && self.undo.is_empty()
+    }
+
+    /// Remembers the project as it is before an edit, and marks the
+    /// document as changed.
+    pub fn record(&mut self) {
+        self.record_from(self.project.clone());
+    }
+
+    /// Records `before` as the project before an edit that already happened.
+    pub fn record_from(&mut self, before: Project) {
+        self.record_edit(before, None);
+    }
+
+    /// Records `before` as the project before an edit of `field`, unless
+    /// the last edit changed the same field, making both one edit.
+    pub fn record_edit(&mut self, before: Project, field: Option<Field>) {
+        if field.is_none() || field != self.editing {
+            self.undo.push(before);
+            if self.undo.len() > MAX_HISTORY {
+                let _ = self.undo.remove(0);
+            }
+        }
+
+        self.editing = field;
+        self.redo.clear();
+        self.is_dirty = true;
+    }
+
+    /// Ends the edit of the last field, so that changing it again is a new
+    /// edit.
+    pub fn finish_edit(&mut self) {
+        self.editing = None;
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Reverts the last recorded edit, returning whether there was one.
+    pub fn undo(&mut self) -> bool {
+        let Some(project) = self.undo.pop() else {
+            return false;
+        };
+
+        self.redo
+            .push(std::mem::replace(&mut self.project, project));
+        self.selected = None;
+        self.editing = None;
+        self.is_dirty = true;
+        self.refresh_assets();
+
+        true
+    }
+
+    /// Reapplies the last undone edit, returning whether there was one.
+    pub fn redo(&mut self) -> bool {
+        let Some(project) = self.redo.pop() else {
+            return false;
+        };
+
+        self.undo
+            .push(std::mem::replace(&mut self.project, project));
+        self.selected = None;
+        self.editing = None;
+        self.is_dirty = true;
+        self.refresh_assets();
+
+        true
+    }
+
+    /// Replaces the project with the one in the file, dropping the history.
+    pub fn reload(&mut self, project: Project, hash: u64) {
+        self.project = project;
+        self.file_hash = Some(hash);
+        self.is_dirty = false;
+        self.selected = None;
+        self.undo.clear();
+        self.redo.clear();
+        self.editing = None;
+        self.refresh_assets();
+    }
+
+    /// Moves the document to `path`, rebasing the asset paths of the
+    /// project and its history.
+    pub fn saved_to(&mut self, path: PathBuf, hash: u64) {
+        if self.path.as_ref() != Some(&path) {
+            let from = self.path.as_deref();
+
+            for project in std::iter::once(&mut self.project)
+                .chain(&mut self.undo)
+                .chain(&mut self.redo)
+            {
+                project.rebase_assets(from, Some(&path));
+            }
+        }
+
+        self.path = Some(path);
+        self.file_hash = Some(hash);
+        self.is_dirty = false;
+        self.refresh_assets();
+    }
+
+    pub fn is_at(&self, path: &Path) -> bool {
+        self.path.as_deref() == Some(path)
+    }
+}
+
+/// The assets of `project` whose files don't exist.
+fn missing_assets(project: &Project, path: Option<&Path>) -> Vec<PathBuf> {
+    project
+        .assets
+        .iter()
+        .filter(|asset| !asset::resolve(asset, path).is_file())
+        .cloned()
+        .collect()
+}
+
+/// The tab bar above the panes, with one tab per open document.
+pub fn tabs(documents: &[Document], active: usize) -> Element<'_, Message> {
+    let tabs = Row::with_children(documents.iter().enumerate().map(
+        |(index, document)| {
+            let name = if document.is_dirty {
+                format!("{} *", document.name())
+            } else {
+                document.name()
+            };
+
+            row![
+                button(text(name))
+                    .on_press(Message::SelectDocument(index))
+                    .style(if index == active {
+                        iced_material::button::filled_tonal
+                    } else {
+                        iced_material::button::text
+                    }),
+                button(text("×"))
+                    .on_press(Message::CloseDocument(index))
+                    .style(iced_material::button::text),
+            ]
+            .align_y(Alignment::Center)
+            .into()
+        },
+    ))
+    .spacing(5);
+
+    row![tabs, button("+").on_press(Message::NewFile)]
+        .spacing(10)
+        .padding([0, 10])
+        .align_y(Alignment::Center)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_undo_and_redo() {
+        let mut document = Document::default();
+
+        document.record();
+        document.project.add_view();
+
+        assert!(document.is_dirty);
+        assert_eq!(document.project.views().len(), 2);
+
+        assert!(document.undo());
+        assert_eq!(document.project.views().len(), 1);
+
+        assert!(document.redo());
+        assert_eq!(document.project.views().len(), 2);
+
+        assert!(!document.redo())
+    }
+
+    #[test]
+    fn recording_clears_redo() {
+        let mut document = Document::default();
+
+        document.record();
+        document.project.add_view();
+        assert!(document.undo());
+
+        document.record();
+        document.project.title = Some(String::from("Edited"));
+
+        assert!(!document.can_redo())
+    }
+
+    #[test]
+    fn typing_in_a_field_is_one_edit() {
+        let mut document = Document::default();
+        let field = Some(Field::ViewName(0));
+
+        for name in ["H", "Ho", "Home"] {
+            let before = document.project.clone();
+            document.project.rename_view(String::from(name));
+            document.record_edit(before, field.clone());
+        }
+
+        assert_eq!(document.undo.len(), 1);
+
+        document.finish_edit();
+        document.record_edit(document.project.clone(), field);
+
+        assert_eq!(document.undo.len(), 2)
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut document = Document::default();
+
+        for _ in 0..MAX_HISTORY + 10 {
+            document.record();
+        }
+
+        assert_eq!(document.undo.len(), MAX_HISTORY)
+    }
+}
@@ -5,6 +5,7 @@ use std::env;
 use std::path::PathBuf;
 
 pub const CONFIG_FILE_NAME: &str = "config.toml";
+pub const AUTOSAVE_FILE_NAME: &str = "autosave.json";
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const GIT_HASH: Option<&str> = option_env!("GIT_HASH");
 
@@ -16,7 +16,15 @@ pub enum Error {
     TomlDe(#[from] toml::de::Error),
     #[error("TOML serialization error: {0}")]
     TomlSer(#[from] toml::ser::Error),
+    #[error("RON parsing error: {0}")]
+    RonDe(#[from] ron::error::SpannedError),
+    #[error("RON serialization error: {0}")]
+    RonSer(#[from] ron::Error),
     RustFmt(Arc<rust_format::Error>),
+    #[error(
+        "This project was saved by a newer version of iced Builder (format version {0}), please update to open it"
+    )]
+    NewerFormat(u64),
     #[error("The element tree contains no matching element")]
     NonExistentElement,
     #[error("The file dialog has been closed without selecting a valid option")]
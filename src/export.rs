@@ -0,0 +1,312 @@
+use std::path::{Path, PathBuf};
+
+use crate::Error;
+use crate::types::asset::{self, AssetKind, AssetMode};
+use crate::types::{ElementName, Project, RenderedElement};
+
+pub const ICED_VERSION: &str = "0.14";
+pub const ASSETS_DIR: &str = "assets";
+
+/// The files that make up a standalone Cargo project for a [`Project`].
+#[derive(Debug, Clone)]
+pub struct Scaffold {
+    pub name: String,
+    pub manifest: String,
+    pub main: String,
+    /// Source paths of referenced assets, along with their destination
+    /// relative to the root of the scaffold.
+    pub assets: Vec<(PathBuf, PathBuf)>,
+}
+
+impl Scaffold {
+    pub fn new(
+        mut project: Project,
+        project_path: Option<&Path>,
+    ) -> Result<Self, Error> {
+        let mut assets: Vec<(PathBuf, PathBuf)> = Vec::new();
+        let mut features = Vec::new();
+
+        if project.element_trees().any(|element_tree| {
+            element_tree.any(&|name| matches!(name, ElementName::Image(_)))
+        }) {
+            features.push("image");
+        }
+        if project.element_trees().any(|element_tree| {
+            element_tree.any(&|name| matches!(name, ElementName::Svg(_)))
+        }) {
+            features.push("svg");
+        }
+
+        let asset_mode = project.asset_mode;
+        for path in project
+            .element_trees_mut()
+            .flat_map(RenderedElement::asset_paths_mut)
+        {
+            if path.is_empty() {
+                continue;
+            }
+
+            let destination = place_asset(
+                Path::new(path.as_str()),
+                project_path,
+                &mut assets,
+            );
+
+            // `include_bytes!` paths are relative to `src/main.rs`
+            *path = match asset_mode {
+                AssetMode::Runtime => destination,
+                AssetMode::Embedded => format!("../{destination}"),
+            };
+        }
+
+        // Fonts are always embedded
+        for path in &mut project.assets {
+            if AssetKind::from_path(path) == Some(AssetKind::Font) {
+                let destination = place_asset(path, project_path, &mut assets);
+
+                *path = PathBuf::from(format!("../{destination}"));
+            }
+        }
+
+        let name = crate_name(project.title.as_deref());
+        let manifest = manifest(&name, &features);
+        let main = project.app_code()?;
+
+        Ok(Self {
+            name,
+            manifest,
+            main,
+            assets,
+        })
+    }
+
+    /// Writes the scaffold into `dir`, creating it if needed.
+    pub async fn write(self, dir: &Path) -> Result<(), Error> {
+        use tokio::fs;
+
+        fs::create_dir_all(dir.join("src")).await?;
+        fs::write(dir.join("Cargo.toml"), self.manifest).await?;
+        fs::write(dir.join("src").join("main.rs"), self.main).await?;
+
+        if !self.assets.is_empty() {
+            fs::create_dir_all(dir.join(ASSETS_DIR)).await?;
+        }
+
+        for (source, destination) in self.assets {
+            if let Err(error) = fs::copy(&source, dir.join(&destination)).await
+            {
+                return Err(format!(
+                    "Could not copy {}: {error}",
+                    source.to_string_lossy()
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Finds where the asset at `path` goes in the scaffold, adding it to
+/// `assets` unless it's already there.
+fn place_asset(
+    path: &Path,
+    project_path: Option<&Path>,
+    assets: &mut Vec<(PathBuf, PathBuf)>,
+) -> String {
+    let source = asset::resolve(path, project_path);
+
+    let destination =
+        match assets.iter().find(|(existing, _)| *existing == source) {
+            Some((_, destination)) => destination.clone(),
+            None => {
+                let destination = asset_destination(&source, assets);
+                assets.push((source, destination.clone()));
+                destination
+            }
+        };
+
+    destination.to_string_lossy().replace('\\', "/")
+}
+
+fn asset_destination(source: &Path, taken: &[(PathBuf, PathBuf)]) -> PathBuf {
+    let stem = source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| String::from("asset"));
+    let extension = source
+        .extension()
+        .map(|extension| format!(".{}", extension.to_string_lossy()))
+        .unwrap_or_default();
+
+    let mut destination =
+        Path::new(ASSETS_DIR).join(format!("{stem}{extension}"));
+    let mut index = 1;
+
+    while taken.iter().any(|(_, existing)| *existing == destination) {
+        destination =
+            Path::new(ASSETS_DIR).join(format!("{stem}-{index}{extension}"));
+        index += 1;
+    }
+
+    destination
+}
+
+fn crate_name(title: Option<&str>) -> String {
+    let mut name = String::new();
+
+    for c in title.unwrap_or_default().trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c.to_ascii_lowercase());
+        } else if !name.is_empty() && !name.ends_with('-') {
+            name.push('-');
+        }
+    }
+
+    let name = name.trim_end_matches('-');
+
+    if name.is_empty() {
+        String::from("new-app")
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("app-{name}")
+    } else {
+        name.to_owned()
+    }
+}
+
+fn manifest(name: &str, features: &[&str]) -> String {
+    let features = features
+        .iter()
+        .map(|feature| format!("\"{feature}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+iced = {{ version = "{ICED_VERSION}", features = [{features}] }}
+"#
+    )
+}
+
+/// Writes the code of the app to a Rust file the user picks.
+///
+/// Assets loaded at runtime keep their paths relative to the project, so
+/// the exported app has to be run from the project's directory.
+pub async fn code_to_file(
+    mut project: Project,
+    project_path: Option<PathBuf>,
+) -> Result<PathBuf, Error> {
+    let path = rfd::AsyncFileDialog::new()
+        .set_title("Export to Rust file...")
+        .add_filter("*.rs", &["rs"])
+        .set_file_name("main.rs")
+        .save_file()
+        .await
+        .as_ref()
+        .map(rfd::FileHandle::path)
+        .map(Path::to_owned)
+        .ok_or(Error::DialogClosed)?;
+
+    // `include_bytes!` finds files relative to the exported file, while
+    // files loaded at runtime are found from the working directory, which
+    // the generated code takes to be the project's directory
+    if project.asset_mode == AssetMode::Embedded {
+        project.rebase_assets(project_path.as_deref(), Some(&path));
+    }
+    let code = project.app_code()?;
+
+    tokio::fs::write(&path, code).await?;
+
+    Ok(path)
+}
+
+pub async fn to_cargo_project(scaffold: Scaffold) -> Result<PathBuf, Error> {
+    let parent = rfd::AsyncFileDialog::new()
+        .set_title("Export as Cargo project into...")
+        .set_can_create_directories(true)
+        .pick_folder()
+        .await
+        .as_ref()
+        .map(rfd::FileHandle::path)
+        .map(Path::to_owned)
+        .ok_or(Error::DialogClosed)?;
+
+    let dir = parent.join(&scaffold.name);
+
+    if dir.exists() && dir.read_dir()?.next().is_some() {
+        return Err(format!(
+            "The folder {} already exists and isn't empty.",
+            dir.to_string_lossy()
+        )
+        .into());
+    }
+
+    scaffold.write(&dir).await?;
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::rendered_element::text;
+
+    #[test]
+    fn crate_name_is_kebab_case() {
+        assert_eq!(crate_name(Some("  My Cool App! ")), "my-cool-app")
+    }
+
+    #[test]
+    fn crate_name_cant_start_with_digit() {
+        assert_eq!(crate_name(Some("2048")), "app-2048")
+    }
+
+    #[test]
+    fn crate_name_has_fallback() {
+        assert_eq!(crate_name(None), "new-app");
+
+        assert_eq!(crate_name(Some("???")), "new-app")
+    }
+
+    #[test]
+    fn fonts_are_copied_into_the_scaffold() {
+        let mut project = Project::new();
+        *project.element_tree_mut() = Some(text("Hello"));
+        project.assets.push(PathBuf::from("fonts/Inter.ttf"));
+
+        let scaffold =
+            Scaffold::new(project, Some(Path::new("/home/me/app.json")))
+                .expect("scaffold");
+
+        assert_eq!(
+            scaffold.assets,
+            vec![(
+                PathBuf::from("/home/me/fonts/Inter.ttf"),
+                Path::new(ASSETS_DIR).join("Inter.ttf")
+            )]
+        );
+        assert!(
+            scaffold
+                .main
+                .contains(r#"include_bytes!("../assets/Inter.ttf")"#)
+        )
+    }
+
+    #[test]
+    fn asset_destinations_dont_collide() {
+        let taken = vec![(
+            PathBuf::from("a/logo.png"),
+            Path::new(ASSETS_DIR).join("logo.png"),
+        )];
+
+        assert_eq!(
+            asset_destination(Path::new("b/logo.png"), &taken),
+            Path::new(ASSETS_DIR).join("logo-1.png")
+        )
+    }
+}
@@ -0,0 +1,482 @@
+//! Accessibility checks of the designed view.
+use iced::advanced::widget::Id;
+use iced::{Background, Color, Gradient, Length, Pixels};
+
+use crate::types::font::Weight;
+use crate::types::style::ButtonStatus;
+use crate::types::{ElementName, Project, RenderedElement};
+use crate::values::Value;
+
+/// The size of texts when the project doesn't set one.
+const DEFAULT_TEXT_SIZE: f32 = 16.0;
+
+/// The contrast WCAG AA needs for normal and large text.
+const NORMAL_CONTRAST: f32 = 4.5;
+const LARGE_CONTRAST: f32 = 3.0;
+/// The smallest size WCAG AA allows for pointer targets.
+const MIN_TARGET_SIZE: f32 = 24.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Issue {
+    LowContrast { ratio: f32, required: f32 },
+    EmptyLabel,
+    MissingDescription,
+    SmallTarget { dimension: &'static str, size: f32 },
+}
+
+/// The view or component of the project an element is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    View(usize),
+    Component(usize),
+}
+
+impl Location {
+    pub fn name(self, project: &Project) -> &str {
+        let (views, index) = match self {
+            Self::View(index) => (project.views(), index),
+            Self::Component(index) => (project.components(), index),
+        };
+
+        views.get(index).map_or("", |view| view.name.as_str())
+    }
+}
+
+/// An issue of an element, which can be selected to fix it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    pub location: Location,
+    pub id: Id,
+    pub element: String,
+    pub issue: Issue,
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.issue {
+            Issue::LowContrast { ratio, required } => write!(
+                f,
+                "{}: text contrast is {ratio:.2}:1, below the {required}:1 WCAG AA needs",
+                self.element
+            ),
+            Issue::EmptyLabel => {
+                write!(f, "{}: the label is empty", self.element)
+            }
+            Issue::MissingDescription => write!(
+                f,
+                "{}: there's no description for screen readers",
+                self.element
+            ),
+            Issue::SmallTarget { dimension, size } => write!(
+                f,
+                "{}: {size}px {dimension} is below the {MIN_TARGET_SIZE}px target size",
+                self.element
+            ),
+        }
+    }
+}
+
+/// The colors text is drawn in and on.
+#[derive(Debug, Clone)]
+struct Surroundings {
+    text: Color,
+    /// Every color behind the text, e.g. the stops of a gradient.
+    backgrounds: Vec<Color>,
+}
+
+impl Surroundings {
+    /// Draws `background` on top of the current backgrounds.
+    fn behind(&self, background: Option<Background>) -> Vec<Color> {
+        let colors = match background {
+            None => return self.backgrounds.clone(),
+            Some(Background::Color(color)) => vec![color],
+            Some(Background::Gradient(Gradient::Linear(linear))) => linear
+                .stops
+                .iter()
+                .flatten()
+                .map(|stop| stop.color)
+                .collect(),
+        };
+
+        colors
+            .into_iter()
+            .flat_map(|color| {
+                self.backgrounds
+                    .iter()
+                    .map(move |behind| blend(color, *behind))
+            })
+            .collect()
+    }
+
+    /// The lowest contrast of `text` against any of the backgrounds.
+    fn contrast(&self, text: Color) -> f32 {
+        self.backgrounds
+            .iter()
+            .map(|background| contrast(blend(text, *background), *background))
+            .fold(f32::INFINITY, f32::min)
+    }
+}
+
+/// Checks every view and component of `project`, as drawn in its theme.
+///
+/// Instances of components are skipped, as their components are checked
+/// on their own.
+pub fn check_project(project: &Project) -> Vec<Problem> {
+    let theme = project.get_theme();
+    let text_size = project.text_size.unwrap_or(DEFAULT_TEXT_SIZE);
+
+    let views = project
+        .views()
+        .iter()
+        .enumerate()
+        .map(|(index, view)| (Location::View(index), view));
+    let components = project
+        .components()
+        .iter()
+        .enumerate()
+        .map(|(index, component)| (Location::Component(index), component));
+
+    views
+        .chain(components)
+        .filter_map(|(location, view)| {
+            Some((location, view.element_tree.as_ref()?))
+        })
+        .flat_map(|(location, element_tree)| {
+            check(element_tree, location, &theme, text_size)
+        })
+        .collect()
+}
+
+/// Checks `element_tree`, found at `location`, as drawn in `theme`, with
+/// `text_size` as the default size of texts.
+fn check(
+    element_tree: &RenderedElement,
+    location: Location,
+    theme: &iced::Theme,
+    text_size: f32,
+) -> Vec<Problem> {
+    let palette = theme.palette();
+    let mut problems = Vec::new();
+
+    check_element(
+        element_tree,
+        location,
+        theme,
+        text_size,
+        &Surroundings {
+            text: palette.text,
+            backgrounds: vec![palette.background],
+        },
+        &mut problems,
+    );
+
+    problems
+}
+
+fn check_element(
+    element: &RenderedElement,
+    location: Location,
+    theme: &iced::Theme,
+    text_size: f32,
+    surroundings: &Surroundings,
+    problems: &mut Vec<Problem>,
+) {
+    let mut report = |issue| {
+        problems.push(Problem {
+            location,
+            id: element.id().clone(),
+            element: element.name().to_string(),
+            issue,
+        });
+    };
+    let size =
+        option::<Pixels>(element, "size").map_or(text_size, |size| size.0);
+    let mut check_contrast =
+        |color: Color, surroundings: &Surroundings, size: f32, weight| {
+            let required = required_contrast(size, weight);
+            let ratio = surroundings.contrast(color);
+
+            if ratio < required {
+                report(Issue::LowContrast { ratio, required });
+            }
+        };
+
+    match element.name() {
+        ElementName::Text(_) => check_contrast(
+            element
+                .style()
+                .text(theme)
+                .color
+                .unwrap_or(surroundings.text),
+            surroundings,
+            size,
+            element.font().weight,
+        ),
+        ElementName::RichText(spans) => {
+            // Only the least readable span is reported
+            let worst = spans
+                .iter()
+                .map(|span| {
+                    (
+                        surroundings
+                            .contrast(span.color.unwrap_or(surroundings.text)),
+                        required_contrast(
+                            span.size.unwrap_or(size),
+                            span.font.weight,
+                        ),
+                    )
+                })
+                .filter(|(ratio, required)| ratio < required)
+                .min_by(|(a, _), (b, _)| a.total_cmp(b));
+
+            if let Some((ratio, required)) = worst {
+                report(Issue::LowContrast { ratio, required });
+            }
+        }
+        ElementName::Button(label) => {
+            let style = element.style().button(theme, ButtonStatus::Active);
+            let inside = Surroundings {
+                text: style.text_color,
+                backgrounds: surroundings.behind(style.background),
+            };
+
+            check_contrast(
+                style.text_color,
+                &inside,
+                size,
+                element.font().weight,
+            );
+
+            if label.trim().is_empty() {
+                report(Issue::EmptyLabel);
+            }
+
+            for dimension in ["width", "height"] {
+                if let Some(Length::Fixed(size)) = option(element, dimension)
+                    && size < MIN_TARGET_SIZE
+                {
+                    report(Issue::SmallTarget { dimension, size });
+                }
+            }
+        }
+        ElementName::Image(_) | ElementName::Svg(_) => {
+            if element
+                .description()
+                .is_none_or(|description| description.trim().is_empty())
+            {
+                report(Issue::MissingDescription);
+            }
+        }
+        ElementName::Container => {
+            let style = element.style().container(theme);
+            let inside = Surroundings {
+                text: style.text_color.unwrap_or(surroundings.text),
+                backgrounds: surroundings.behind(style.background),
+            };
+
+            for child in element.child_elements() {
+                check_element(
+                    child, location, theme, text_size, &inside, problems,
+                );
+            }
+        }
+        ElementName::Row | ElementName::Column => {
+            for child in element.child_elements() {
+                check_element(
+                    child,
+                    location,
+                    theme,
+                    text_size,
+                    surroundings,
+                    problems,
+                );
+            }
+        }
+        ElementName::Component(_) => {}
+    }
+}
+
+/// The contrast WCAG AA needs for text of `size` and `weight`, which is
+/// lower for large or bold text.
+fn required_contrast(size: f32, weight: Weight) -> f32 {
+    let is_bold =
+        matches!(weight, Weight::Bold | Weight::ExtraBold | Weight::Black);
+
+    if size >= 24.0 || (is_bold && size >= 18.66) {
+        LARGE_CONTRAST
+    } else {
+        NORMAL_CONTRAST
+    }
+}
+
+/// The value of an option of `element`, if it's set and valid.
+fn option<T: Value>(element: &RenderedElement, name: &str) -> Option<T> {
+    element
+        .overrides()
+        .get(name)
+        .cloned()
+        .flatten()
+        .and_then(|value| T::from_str(&value).ok())
+}
+
+/// Draws `color` on top of the opaque `background`.
+fn blend(color: Color, background: Color) -> Color {
+    let mix = |top: f32, bottom: f32| top * color.a + bottom * (1.0 - color.a);
+
+    Color::from_rgb(
+        mix(color.r, background.r),
+        mix(color.g, background.g),
+        mix(color.b, background.b),
+    )
+}
+
+/// The relative luminance of an sRGB color, as WCAG defines it.
+fn luminance(color: Color) -> f32 {
+    let linear = |channel: f32| {
+        if channel <= 0.04045 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * linear(color.r)
+        + 0.7152 * linear(color.g)
+        + 0.0722 * linear(color.b)
+}
+
+fn contrast(a: Color, b: Color) -> f32 {
+    let (a, b) = (luminance(a), luminance(b));
+
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::rendered_element::{
+        button, column, container, image, text,
+    };
+    use crate::types::style::{Appearance, ColorRef, ElementStyle};
+
+    fn styled(
+        mut element: RenderedElement,
+        appearance: Appearance,
+    ) -> RenderedElement {
+        element.set_style(ElementStyle {
+            base: appearance,
+            ..ElementStyle::default()
+        });
+        element
+    }
+
+    #[test]
+    fn black_on_white_has_the_highest_contrast() {
+        assert!((contrast(Color::BLACK, Color::WHITE) - 21.0).abs() < 0.01);
+        assert_eq!(contrast(Color::WHITE, Color::WHITE), 1.0)
+    }
+
+    #[test]
+    fn reports_low_contrast_text() {
+        let gray = Color::from_rgb8(0xaa, 0xaa, 0xaa);
+        let tree = column(vec![
+            styled(
+                text("Faint"),
+                Appearance {
+                    text_color: Some(ColorRef::Literal(gray)),
+                    ..Appearance::default()
+                },
+            ),
+            text("Readable"),
+        ]);
+
+        let problems =
+            check(&tree, Location::View(0), &iced::Theme::Light, 16.0);
+
+        assert_eq!(problems.len(), 1);
+        assert!(matches!(
+            problems[0].issue,
+            Issue::LowContrast { required, .. } if required == NORMAL_CONTRAST
+        ))
+    }
+
+    #[test]
+    fn large_text_needs_less_contrast() {
+        let gray = Color::from_rgb8(0x90, 0x90, 0x90);
+        let faint = styled(
+            text("Title"),
+            Appearance {
+                text_color: Some(ColorRef::Literal(gray)),
+                ..Appearance::default()
+            },
+        );
+
+        assert_eq!(
+            check(&faint, Location::View(0), &iced::Theme::Light, 16.0).len(),
+            1
+        );
+        assert!(
+            check(&faint, Location::View(0), &iced::Theme::Light, 32.0)
+                .is_empty()
+        )
+    }
+
+    #[test]
+    fn uses_the_background_of_containers() {
+        let dark = styled(
+            container(Some(text("Hidden"))),
+            Appearance {
+                background: Some(crate::types::style::Background::Color(
+                    ColorRef::Literal(Color::BLACK),
+                )),
+                ..Appearance::default()
+            },
+        );
+
+        // Light theme text is dark
+        assert_eq!(
+            check(&dark, Location::View(0), &iced::Theme::Light, 16.0).len(),
+            1
+        )
+    }
+
+    #[test]
+    fn checks_every_view_and_component() {
+        let mut project = Project::new();
+        *project.element_tree_mut() = Some(image("logo.png"));
+        project.add_view();
+        let logo = image("logo.png");
+        let id = logo.id().clone();
+        *project.element_tree_mut() =
+            Some(column(vec![text("Readable"), logo]));
+        project.promote_to_component(&id, "Logo").unwrap();
+
+        let locations: Vec<Location> = check_project(&project)
+            .into_iter()
+            .map(|problem| problem.location)
+            .collect();
+
+        assert_eq!(locations, vec![Location::View(0), Location::Component(0)])
+    }
+
+    #[test]
+    fn reports_buttons_and_images() {
+        let tree = column(vec![
+            button("").option(String::from("height"), String::from("fx16")),
+            image("logo.png"),
+        ]);
+
+        let issues: Vec<Issue> =
+            check(&tree, Location::View(0), &iced::Theme::Light, 16.0)
+                .into_iter()
+                .map(|problem| problem.issue)
+                .collect();
+
+        assert!(issues.contains(&Issue::EmptyLabel));
+        assert!(issues.contains(&Issue::MissingDescription));
+        assert!(issues.contains(&Issue::SmallTarget {
+            dimension: "height",
+            size: 16.0
+        }))
+    }
+}
@@ -3,35 +3,65 @@
 mod appearance;
 mod config;
 mod dialog;
+mod diff;
+mod document;
 mod environment;
 mod error;
+mod export;
 mod icon;
+mod lint;
 mod options;
 mod panes;
+mod project_settings;
+mod runner;
+mod template;
 mod types;
 mod values;
 #[allow(dead_code)]
 mod widget;
 
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use config::Config;
 use dialog::{Dialog, UnsavedChanges};
+use document::{Document, Field};
 use error::Error;
+use export::Scaffold;
 use iced::advanced::widget::Id;
-use iced::widget::{Column, container, pane_grid, pick_list, row, text_editor};
+use iced::widget::{
+    Column, button, container, horizontal_space, pane_grid, pick_list, row,
+    text, text_editor,
+};
 use iced::{
     Alignment, Length, Subscription, Task, clipboard, keyboard, window,
 };
 use iced_anim::transition::Easing;
 use iced_anim::{Animated, Animation};
 use iced_material::Theme;
-use panes::{code_view, designer_view, element_list};
+use lint::Location;
+use panes::designer_view::Canvas;
+use panes::{
+    asset_library, code_view, designer_view, element_list, inspector, problems,
+    run_log,
+};
+use project_settings::ProjectSettings;
+use runner::Runner;
+use template::Template;
+use types::asset::{self, AssetKind};
+use types::component::is_valid_override;
+use types::span;
+use types::style::ButtonStatus;
+use types::viewport::Viewport;
 use types::{
-    Action, ConfigChangeType, DesignerPane, Element, Message, Panes, Project,
+    Action, ConfigChangeType, DesignerPane, Element, ElementName, Message,
+    Panes, Project, ViewContext,
 };
 
+/// How often the open project file is checked for changes made elsewhere.
+const PROJECT_FILE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut project_path = None;
 
@@ -73,17 +103,56 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 struct IcedBuilder {
-    is_dirty: bool,
     is_loading: bool,
-    project_path: Option<PathBuf>,
-    project: Project,
+    /// Whether the autosave file is being written or removed.
+    is_autosaving: bool,
+    /// Whether the documents changed while it was, so the autosave file is
+    /// brought in line again once it's done.
+    autosave_is_stale: bool,
+    /// Whether an autosave error was shown since the last autosave that
+    /// worked, so a failing autosave doesn't keep interrupting.
+    autosave_failed: bool,
+    /// Whether iced Builder closes once the autosave in flight is done.
+    is_closing: bool,
+    /// Project given on the command line.
+    startup_path: Option<PathBuf>,
+    /// The open projects, there's always at least one.
+    documents: Vec<Document>,
+    /// The document shown in the panes.
+    active: usize,
+    templates: Vec<Template>,
+    /// Name for the component made from the selected element.
+    component_name: String,
+    /// The key and text of an override being typed that isn't valid yet,
+    /// which is kept out of the project until it is.
+    override_draft: Option<(String, String)>,
+    /// Whether the message being handled comes from a dragged slider.
+    sliding: bool,
+    /// The project settings being edited in the dialog.
+    project_settings: ProjectSettings,
     config: Config,
     theme: Animated<Theme>,
     pane_state: pane_grid::State<Panes>,
     focus: Option<pane_grid::Pane>,
     designer_page: DesignerPane,
+    /// The status buttons are previewed and styled in.
+    button_status: ButtonStatus,
+    /// The themes the designer draws the view in side by side, or none to
+    /// only use the project's.
+    compared_themes: Vec<iced::Theme>,
+    /// The window size and zoom the designer previews the view at.
+    viewport: Viewport,
+    /// Whether the designer draws the view as the generated code does.
+    preview: bool,
+    /// Whether the designer draws the layout of the view on top of it.
+    layout_debug: bool,
+    /// The size of the element under the cursor, and the preview it's in.
+    hovered_size: Option<(usize, iced::Size)>,
+    /// Bundled fonts already loaded into the designer.
+    loaded_fonts: Vec<PathBuf>,
     dialog: Dialog,
     editor_content: text_editor::Content,
+    runner: Runner,
 }
 
 impl IcedBuilder {
@@ -93,16 +162,40 @@ impl IcedBuilder {
                 axis: pane_grid::Axis::Vertical,
                 ratio: 0.8,
                 a: Box::new(pane_grid::Configuration::Pane(Panes::Designer)),
-                b: Box::new(pane_grid::Configuration::Pane(Panes::ElementList)),
+                b: Box::new(pane_grid::Configuration::Split {
+                    axis: pane_grid::Axis::Horizontal,
+                    ratio: 0.35,
+                    a: Box::new(pane_grid::Configuration::Pane(
+                        Panes::ElementList,
+                    )),
+                    b: Box::new(pane_grid::Configuration::Split {
+                        axis: pane_grid::Axis::Horizontal,
+                        ratio: 0.6,
+                        a: Box::new(pane_grid::Configuration::Pane(
+                            Panes::Inspector,
+                        )),
+                        b: Box::new(pane_grid::Configuration::Pane(
+                            Panes::AssetLibrary,
+                        )),
+                    }),
+                }),
             },
         );
 
         let config = Config::default();
         let theme = config.selected_theme();
+        let dialog = if Project::has_autosave() {
+            Dialog::recover()
+        } else {
+            Dialog::default()
+        };
 
-        let mut tasks =
-            vec![Task::perform(Config::load(), Message::ConfigLoad)];
+        let mut tasks = vec![
+            Task::perform(Config::load(), Message::ConfigLoad),
+            Task::perform(template::list(), Message::TemplatesLoaded),
+        ];
         if let Some(path) = project_path.as_deref()
+            && dialog.action() != dialog::Action::Recover
             && path.exists()
             && path.is_file()
         {
@@ -114,26 +207,44 @@ impl IcedBuilder {
 
         (
             Self {
-                is_dirty: false,
                 is_loading: false,
-                project_path,
-                project: Project::new(),
+                is_autosaving: false,
+                autosave_is_stale: false,
+                autosave_failed: false,
+                is_closing: false,
+                startup_path: project_path,
+                documents: vec![Document::default()],
+                active: 0,
+                templates: Vec::new(),
+                component_name: String::new(),
+                override_draft: None,
+                sliding: false,
+                project_settings: ProjectSettings::default(),
                 config,
                 theme: Animated::new(theme, Easing::EASE_IN),
                 pane_state: state,
                 focus: None,
                 designer_page: DesignerPane::DesignerView,
-                dialog: Dialog::default(),
+                button_status: ButtonStatus::default(),
+                compared_themes: Vec::new(),
+                viewport: Viewport::default(),
+                preview: false,
+                layout_debug: false,
+                hovered_size: None,
+                loaded_fonts: Vec::new(),
+                dialog,
                 editor_content: text_editor::Content::new(),
+                runner: Runner::default(),
             },
             Task::batch(tasks),
         )
     }
 
     fn title(&self) -> String {
-        let saved_state = if self.is_dirty { " *" } else { "" };
+        let document = self.document();
+        let saved_state = if document.is_dirty { " *" } else { "" };
 
-        let project_name = match &self.project.title {
+        let project_name = match &document.project.title {
             Some(n) => {
                 format!(
                     " - {}",
@@ -154,6 +265,176 @@ impl IcedBuilder {
         self.theme.value().clone()
     }
 
+    fn find_pane(&self, kind: Panes) -> Option<pane_grid::Pane> {
+        self.pane_state
+            .iter()
+            .find(|(_, pane)| **pane == kind)
+            .map(|(id, _)| *id)
+    }
+
+    fn document(&self) -> &Document {
+        &self.documents[self.active]
+    }
+
+    fn document_mut(&mut self) -> &mut Document {
+        &mut self.documents[self.active]
+    }
+
+    fn find_document(&mut self, key: document::Key) -> Option<&mut Document> {
+        self.documents
+            .iter_mut()
+            .find(|document| document.key() == key)
+    }
+
+    /// Shows `document` in a new tab, or instead of the active document if
+    /// that one is untouched.
+    fn open_document(&mut self, document: Document) {
+        if self.document().is_pristine() {
+            self.documents[self.active] = document;
+        } else {
+            self.documents.push(document);
+            self.active = self.documents.len() - 1;
+        }
+    }
+
+    fn close_document(&mut self, index: usize) {
+        let _ = self.documents.remove(index);
+
+        if self.documents.is_empty() {
+            self.documents.push(Document::default());
+        }
+        if self.active > index {
+            self.active -= 1;
+        }
+        self.active = self.active.min(self.documents.len() - 1);
+    }
+
+    /// Saves the active document, to a file picked by the user if
+    /// `pick_path` is set.
+    fn save(&mut self, pick_path: bool) -> Task<Message> {
+        self.is_loading = true;
+
+        let document = self.document();
+        let key = document.key();
+
+        Task::perform(
+            document.project.clone().write_to_file(
+                if pick_path {
+                    None
+                } else {
+                    document.path.clone()
+                },
+                document.path.clone(),
+            ),
+            move |result| Message::FileSaved(key, result),
+        )
+    }
+
+    /// Loads the fonts bundled with the active project that the designer
+    /// hasn't loaded yet.
+    fn load_fonts(&mut self) -> Task<Message> {
+        let document = &self.documents[self.active];
+        let paths: Vec<PathBuf> = document
+            .project
+            .font_assets()
+            .map(|path| asset::resolve(path, document.path.as_deref()))
+            .filter(|path| !self.loaded_fonts.contains(path))
+            .collect();
+        self.loaded_fonts.extend(paths.iter().cloned());
+
+        Task::batch(paths.into_iter().map(|path| {
+            let name = path.to_string_lossy().into_owned();
+
+            Task::future(tokio::fs::read(path)).then(move |result| {
+                let name = name.clone();
+
+                match result {
+                    Ok(bytes) => iced::font::load(bytes).map(move |result| {
+                        Message::FontLoaded(result.map_err(|_| {
+                            format!("{name} is not a valid font").into()
+                        }))
+                    }),
+                    Err(error) => {
+                        Task::done(Message::FontLoaded(Err(Error::from(error))))
+                    }
+                }
+            })
+        }))
+    }
+
+    /// Brings the autosave file in line with the documents that still have
+    /// unsaved changes.
+    ///
+    /// Only one write or removal runs at a time, so an older one can't land
+    /// after a newer one.
+    fn refresh_autosave(&mut self) -> Task<Message> {
+        if self.is_autosaving {
+            self.autosave_is_stale = true;
+
+            return Task::none();
+        }
+
+        let dirty: Vec<_> = self
+            .documents
+            .iter()
+            .filter(|document| document.is_dirty)
+            .map(|document| (document.path.clone(), document.project.clone()))
+            .collect();
+
+        self.is_autosaving = true;
+
+        if dirty.is_empty() {
+            Task::perform(Project::discard_autosave(), Message::Autosaved)
+        } else {
+            Task::perform(Project::autosave(dirty), Message::Autosaved)
+        }
+    }
+
+    /// Asks what to do with the next document with unsaved changes before
+    /// closing iced Builder, or closes it if there are none left.
+    fn exit_next_dirty(&mut self) -> Task<Message> {
+        match self.documents.iter().position(|document| document.is_dirty) {
+            Some(index) => {
+                self.active = index;
+                self.dialog = Dialog::unsaved_changes(
+                    format!(
+                        "{} has unsaved changes. Do you want to save them before closing iced Builder?",
+                        self.document().name()
+                    ),
+                    UnsavedChanges::Exit,
+                );
+
+                Task::done(Message::RefreshEditorContent)
+            }
+            None => self.update(Message::CloseApp),
+        }
+    }
+
+    fn is_recovering(&self) -> bool {
+        self.dialog.is_open() && self.dialog.action() == dialog::Action::Recover
+    }
+
+    /// Opens the project given on the command line, or the last opened one.
+    fn open_startup_project(&mut self) -> Task<Message> {
+        let Some(path) = self
+            .startup_path
+            .clone()
+            .or_else(|| self.config.last_project().map(Path::to_path_buf))
+        else {
+            return Task::none();
+        };
+
+        if path.exists() && path.is_file() {
+            Task::perform(Project::from_path(path), Message::FileOpened)
+        } else {
+            self.dialog = Dialog::warning(format!(
+                "The file {} does not exist, or isn't a file.",
+                path.to_string_lossy()
+            ));
+            Task::none()
+        }
+    }
+
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::ConfigLoad(result) => match result {
@@ -161,21 +442,9 @@ impl IcedBuilder {
                     self.config = config;
                     self.theme.settle_at(self.config.selected_theme());
 
-                    if let Some(path) = self.config.last_project()
-                        && self.project_path.is_none()
-                    {
-                        if path.exists() && path.is_file() {
-                            return Task::perform(
-                                Project::from_path(path.to_owned()),
-                                Message::FileOpened,
-                            );
-                        } else {
-                            self.dialog = Dialog::warning(format!(
-                                "The file {} does not exist, or isn't a file.",
-                                path.to_string_lossy()
-                            ));
-                        };
-                    };
+                    if self.startup_path.is_none() && !self.is_recovering() {
+                        return self.open_startup_project();
+                    }
                 }
                 Err(error) => self.dialog = Dialog::error(error),
             },
@@ -186,8 +455,10 @@ impl IcedBuilder {
             }
             Message::SaveConfigChanges(change) => {
                 match change {
-                    ConfigChangeType::LastProject => {
-                        self.config.last_project = self.project_path.clone();
+                    ConfigChangeType::RecentProjects => {
+                        if let Some(path) = &self.documents[self.active].path {
+                            self.config.add_recent_project(path);
+                        }
                     }
                     ConfigChangeType::SelectedTheme => {
                         self.config.appearance.selected =
@@ -211,19 +482,139 @@ impl IcedBuilder {
             Message::CopyCode => {
                 return clipboard::write(self.editor_content.text());
             }
+            Message::ExportCode => {
+                if !self.is_loading {
+                    self.is_loading = true;
+
+                    return Task::perform(
+                        export::code_to_file(
+                            self.document().project.clone(),
+                            self.document().path.clone(),
+                        ),
+                        Message::Exported,
+                    );
+                }
+            }
+            Message::ExportCargoProject => {
+                if !self.is_loading {
+                    match Scaffold::new(
+                        self.document().project.clone(),
+                        self.document().path.as_deref(),
+                    ) {
+                        Ok(scaffold) => {
+                            self.is_loading = true;
+
+                            return Task::perform(
+                                export::to_cargo_project(scaffold),
+                                Message::Exported,
+                            );
+                        }
+                        Err(error) => self.dialog = Dialog::error(error),
+                    }
+                }
+            }
+            Message::Exported(result) => {
+                self.is_loading = false;
+
+                match result {
+                    Ok(_) | Err(Error::DialogClosed) => {}
+                    Err(error) => self.dialog = Dialog::error(error),
+                }
+            }
+            Message::Run => {
+                match Scaffold::new(
+                    self.document().project.clone(),
+                    self.document().path.as_deref(),
+                ) {
+                    Ok(scaffold) => {
+                        if self.find_pane(Panes::RunLog).is_none()
+                            && let Some(designer) =
+                                self.find_pane(Panes::Designer)
+                        {
+                            let _ = self.pane_state.split(
+                                pane_grid::Axis::Horizontal,
+                                designer,
+                                Panes::RunLog,
+                            );
+                        }
+
+                        return self
+                            .runner
+                            .start(scaffold)
+                            .map(Message::RunEvent);
+                    }
+                    Err(error) => self.dialog = Dialog::error(error),
+                }
+            }
+            Message::StopRun => self.runner.stop(),
+            Message::RunEvent(event) => self.runner.update(event),
+            Message::CloseRunLog => {
+                self.runner.stop();
+
+                if let Some(pane) = self.find_pane(Panes::RunLog) {
+                    let _ = self.pane_state.close(pane);
+                }
+            }
+            Message::ShowProblems => {
+                if self.find_pane(Panes::Problems).is_none()
+                    && let Some(inspector) = self.find_pane(Panes::Inspector)
+                {
+                    let _ = self.pane_state.split(
+                        pane_grid::Axis::Horizontal,
+                        inspector,
+                        Panes::Problems,
+                    );
+                }
+            }
+            Message::CloseProblems => {
+                if let Some(pane) = self.find_pane(Panes::Problems) {
+                    let _ = self.pane_state.close(pane);
+                }
+            }
             Message::SwitchPane(pane) => self.designer_page = pane,
+            Message::PreviewButtonStatus(status) => {
+                self.button_status = status;
+            }
+            Message::CompareThemes(themes) => self.compared_themes = themes,
+            Message::SetViewport(viewport) => self.viewport = viewport,
+            Message::SetPreview(preview) => self.preview = preview,
+            Message::SetLayoutDebug(layout_debug) => {
+                self.layout_debug = layout_debug;
+                self.hovered_size = None;
+            }
+            Message::LayoutHovered(preview, Some(size)) => {
+                self.hovered_size = Some((preview, size));
+            }
+            // Only the preview the cursor left clears what it hovered
+            Message::LayoutHovered(preview, None) => {
+                if self
+                    .hovered_size
+                    .is_some_and(|(hovered, _)| hovered == preview)
+                {
+                    self.hovered_size = None;
+                }
+            }
             Message::EditorAction(action) => {
                 if !action.is_edit() {
                     self.editor_content.perform(action);
                 }
             }
-            Message::RefreshEditorContent => match self.project.app_code() {
-                Ok(code) => {
-                    self.editor_content =
-                        text_editor::Content::with_text(&code);
+            Message::RefreshEditorContent => {
+                let document = &mut self.documents[self.active];
+                document.refresh_problems();
+
+                if document.project.is_blank() {
+                    self.editor_content = text_editor::Content::new();
+                } else {
+                    match document.project.app_code() {
+                        Ok(code) => {
+                            self.editor_content =
+                                text_editor::Content::with_text(&code);
+                        }
+                        Err(error) => self.dialog = Dialog::error(error),
+                    }
                 }
-                Err(error) => self.dialog = Dialog::error(error),
-            },
+            }
             Message::DropNewElement(name, point, _) => {
                 return iced_drop::zones_on_point(
                     move |zones| Message::HandleNew(name.clone(), zones),
@@ -232,32 +623,47 @@ impl IcedBuilder {
                     None,
                 );
             }
+            Message::HandleNew(ElementName::Component(_), _)
+                if self.document().project.editing_component().is_some() =>
+            {
+                self.dialog = Dialog::error(
+                    "Components can't contain instances of other components",
+                );
+            }
             Message::HandleNew(name, zones) => {
                 let ids: Vec<Id> = zones.into_iter().map(|z| z.0).collect();
                 if !ids.is_empty() {
-                    self.is_dirty = true;
+                    let document = &mut self.documents[self.active];
+                    let before = document.project.clone();
 
                     let action = Action::new(
                         &ids,
-                        self.project.element_tree.as_ref(),
+                        document.project.element_tree(),
                         None,
                     );
 
                     let result = name.handle_action(
-                        self.project.element_tree.as_mut(),
+                        document.project.element_tree_mut().as_mut(),
                         action,
                     );
 
                     match result {
                         Ok(Some(ref element)) => {
-                            self.project.element_tree = Some(element.clone());
+                            *document.project.element_tree_mut() =
+                                Some(element.clone());
                         }
                         Err(error) => {
                             self.dialog = Dialog::error(error);
                         }
                         _ => {}
                     }
-                    return self.update(Message::RefreshEditorContent);
+
+                    if document.project.element_tree() != before.element_tree()
+                    {
+                        document.record_from(before);
+
+                        return self.update(Message::RefreshEditorContent);
+                    }
                 }
             }
             Message::MoveElement(element, point, _) => {
@@ -271,14 +677,17 @@ impl IcedBuilder {
             Message::HandleMove(element, zones) => {
                 let ids: Vec<Id> = zones.into_iter().map(|z| z.0).collect();
                 if !ids.is_empty() {
+                    let document = &mut self.documents[self.active];
+                    let before = document.project.clone();
+
                     let action = Action::new(
                         &ids,
-                        self.project.element_tree.as_ref(),
+                        document.project.element_tree(),
                         Some(element.id()),
                     );
 
                     let result = element.handle_action(
-                        self.project.element_tree.as_mut(),
+                        document.project.element_tree_mut().as_mut(),
                         action,
                     );
 
@@ -286,10 +695,310 @@ impl IcedBuilder {
                         self.dialog = Dialog::error(error);
                     }
 
-                    self.is_dirty = true;
+                    if document.project.element_tree() != before.element_tree()
+                    {
+                        document.record_from(before);
+
+                        return self.update(Message::RefreshEditorContent);
+                    }
+                }
+            }
+            Message::SelectView(index) => {
+                let document = self.document_mut();
+                document.project.select_view(index);
+                document.selected = None;
+            }
+            Message::AddView => {
+                let document = self.document_mut();
+                document.record();
+                document.project.add_view();
+
+                return self.update(Message::RefreshEditorContent);
+            }
+            Message::RenameView(name) => {
+                let document = self.document_mut();
+                let before = document.project.clone();
+                let view = document.project.current_view();
+                document.project.rename_view(name);
+                document.record_edit(before, Some(Field::ViewName(view)));
+
+                return self.update(Message::RefreshEditorContent);
+            }
+            Message::RemoveView => {
+                let project = &self.document().project;
+
+                if project.element_tree().is_some() {
+                    self.dialog = Dialog::remove_view(format!(
+                        "Do you really want to remove the view \"{}\" and everything in it?",
+                        project.views()[project.current_view()].name
+                    ));
+                } else {
+                    let document = self.document_mut();
+                    document.record();
+                    document.project.remove_view();
+
+                    return self.update(Message::RefreshEditorContent);
+                }
+            }
+            Message::SelectElement(id) => {
+                // Leaving an element is done with typing its font
+                let _ = self.update(Message::CommitFonts);
+
+                self.document_mut().selected = Some(id);
+                self.component_name.clear();
+                self.override_draft = None;
+            }
+            Message::ShowProblem(location, id) => {
+                let project = &mut self.document_mut().project;
+
+                match location {
+                    Location::View(index) => project.select_view(index),
+                    Location::Component(index) => project.edit_component(index),
+                }
+
+                return self.update(Message::SelectElement(id));
+            }
+            Message::ComponentNameChanged(name) => self.component_name = name,
+            Message::PromoteToComponent => {
+                let document = &mut self.documents[self.active];
+                let Some(id) = document.selected.clone() else {
+                    return Task::none();
+                };
+                let before = document.project.clone();
+
+                match document
+                    .project
+                    .promote_to_component(&id, &self.component_name)
+                {
+                    Ok(()) => {
+                        document.record_from(before);
+                        document.selected = None;
+                        self.component_name.clear();
+
+                        return self.update(Message::RefreshEditorContent);
+                    }
+                    Err(error) => self.dialog = Dialog::error(error),
+                }
+            }
+            Message::EditComponent(index) => {
+                let document = self.document_mut();
+                document.project.edit_component(index);
+                document.selected = None;
+            }
+            Message::StopEditingComponent => {
+                let document = self.document_mut();
+                document.project.stop_editing_component();
+                document.selected = None;
+            }
+            Message::SetOverride(key, value) => {
+                if !is_valid_override(&key, &value) {
+                    self.override_draft = Some((key, value));
+                    return Task::none();
+                }
+                self.override_draft = None;
+
+                let document = self.document_mut();
+                let Some(id) = document.selected.clone() else {
+                    return Task::none();
+                };
+                let before = document.project.clone();
+
+                if let Some(instance) = document
+                    .project
+                    .element_tree_mut()
+                    .as_mut()
+                    .and_then(|element_tree| element_tree.find_by_id(&id))
+                {
+                    let field = Field::Override(id, key.clone());
+                    instance.set_override(key, value);
+                    document.record_edit(before, Some(field));
+
+                    return self.update(Message::RefreshEditorContent);
+                }
+            }
+            Message::SetStyle(style) => {
+                let field = self.sliding.then_some(Field::Slider);
+                let document = self.document_mut();
+                let Some(id) = document.selected.clone() else {
+                    return Task::none();
+                };
+                let before = document.project.clone();
+
+                if let Some(element) = document
+                    .project
+                    .element_tree_mut()
+                    .as_mut()
+                    .and_then(|element_tree| element_tree.find_by_id(&id))
+                    && *element.style() != style
+                {
+                    element.set_style(style);
+                    document.record_edit(before, field);
+
+                    return self.update(Message::RefreshEditorContent);
+                }
+            }
+            Message::Sliding(message) => {
+                self.sliding = true;
+                let task = self.update(*message);
+                self.sliding = false;
+
+                return task;
+            }
+            Message::SliderReleased => self.document_mut().finish_edit(),
+            Message::SetFont(font) => {
+                let document = self.document_mut();
+                let Some(id) = document.selected.clone() else {
+                    return Task::none();
+                };
+                let before = document.project.clone();
+
+                if let Some(element) = document
+                    .project
+                    .element_tree_mut()
+                    .as_mut()
+                    .and_then(|element_tree| element_tree.find_by_id(&id))
+                    && *element.font() != font
+                {
+                    // Typing a family name is one edit
+                    let field = element
+                        .font()
+                        .is_renamed_to(&font)
+                        .then(|| Field::FontFamily(id));
+                    element.set_font(font);
+                    document.record_edit(before, field);
+
+                    return self.update(Message::RefreshEditorContent);
+                }
+            }
+            Message::CommitFonts => {
+                let document = self.document_mut();
+                document.finish_edit();
+
+                if let Some(id) = document.selected.clone()
+                    && let Some(element) = document
+                        .project
+                        .element_tree()
+                        .and_then(|element_tree| element_tree.find(&id))
+                {
+                    element.commit_fonts();
+                }
+            }
+            Message::SetSpans(spans) => {
+                let sliding = self.sliding;
+                let document = self.document_mut();
+                let Some(id) = document.selected.clone() else {
+                    return Task::none();
+                };
+                let before = document.project.clone();
+
+                if let Some(element) = document
+                    .project
+                    .element_tree_mut()
+                    .as_mut()
+                    .and_then(|element_tree| element_tree.find_by_id(&id))
+                    && let ElementName::RichText(current) = element.name()
+                    && *current != spans
+                {
+                    let field = if sliding {
+                        Some(Field::Slider)
+                    } else {
+                        // Typing in a segment is one edit
+                        span::retyped(current, &spans)
+                            .map(|index| Field::SpanText(id, index))
+                    };
+                    element.set_spans(spans);
+                    document.record_edit(before, field);
+
+                    return self.update(Message::RefreshEditorContent);
+                }
+            }
+            Message::SetDescription(description) => {
+                let document = self.document_mut();
+                let Some(id) = document.selected.clone() else {
+                    return Task::none();
+                };
+                let before = document.project.clone();
+                let description =
+                    (!description.is_empty()).then_some(description);
+
+                if let Some(element) = document
+                    .project
+                    .element_tree_mut()
+                    .as_mut()
+                    .and_then(|element_tree| element_tree.find_by_id(&id))
+                    && element.description() != description.as_deref()
+                {
+                    element.set_description(description);
+                    document.record_edit(before, Some(Field::Description(id)));
+
+                    return self.update(Message::RefreshEditorContent);
+                }
+            }
+            Message::FontLoaded(result) => {
+                if let Err(error) = result {
+                    self.dialog = Dialog::error(error);
+                }
+            }
+            Message::AddAssets => {
+                return Task::perform(asset::pick(), Message::AssetsPicked);
+            }
+            Message::AssetsPicked(result) => match result {
+                Ok(paths) => {
+                    let document = self.document_mut();
+                    let paths: Vec<PathBuf> = paths
+                        .iter()
+                        .map(|path| {
+                            asset::relativize(path, document.path.as_deref())
+                        })
+                        .filter(|path| !document.project.assets.contains(path))
+                        .collect();
+
+                    if !paths.is_empty() {
+                        document.record();
+                        document.project.assets.extend(paths);
+                        document.refresh_assets();
+
+                        return self
+                            .load_fonts()
+                            .chain(self.update(Message::RefreshEditorContent));
+                    }
+                }
+                Err(Error::DialogClosed) => {}
+                Err(error) => self.dialog = Dialog::error(error),
+            },
+            Message::SetAssetMode(asset_mode) => {
+                let document = self.document_mut();
+                document.record();
+                document.project.asset_mode = asset_mode;
+
+                return self.update(Message::RefreshEditorContent);
+            }
+            Message::RemoveAsset(index) => {
+                let document = self.document_mut();
+
+                if index < document.project.assets.len() {
+                    document.record();
+                    let _ = document.project.assets.remove(index);
+                    document.refresh_assets();
+
                     return self.update(Message::RefreshEditorContent);
                 }
             }
+            Message::DropAsset(path, point, _) => {
+                let path_string = path.to_string_lossy().into_owned();
+                let name = match AssetKind::from_path(&path) {
+                    Some(AssetKind::Image) => ElementName::Image(path_string),
+                    Some(AssetKind::Svg) => ElementName::Svg(path_string),
+                    Some(AssetKind::Font) | None => return Task::none(),
+                };
+
+                return iced_drop::zones_on_point(
+                    move |zones| Message::HandleNew(name.clone(), zones),
+                    point,
+                    None,
+                    None,
+                );
+            }
             Message::PaneResized(pane_grid::ResizeEvent { split, ratio }) => {
                 self.pane_state.resize(split, ratio);
             }
@@ -300,141 +1009,455 @@ impl IcedBuilder {
             }) => self.pane_state.drop(pane, target),
             Message::PaneDragged(_) => {}
             Message::CloseDialog => self.dialog.close(),
+            Message::DialogYes
+                if self.dialog.action() == dialog::Action::Recover =>
+            {
+                self.is_loading = true;
+
+                return Task::perform(Project::recover(), Message::Recovered);
+            }
+            Message::DialogNo
+                if self.dialog.action() == dialog::Action::Recover =>
+            {
+                self.dialog.close();
+
+                return self
+                    .refresh_autosave()
+                    .chain(self.open_startup_project());
+            }
+            Message::DialogYes
+                if self.dialog.action() == dialog::Action::RemoveView =>
+            {
+                let document = self.document_mut();
+                document.record();
+                document.project.remove_view();
+                self.dialog.close();
+
+                return self.update(Message::RefreshEditorContent);
+            }
+            Message::DialogYes
+                if self.dialog.action() == dialog::Action::ProjectSettings =>
+            {
+                let document = &mut self.documents[self.active];
+                let before = document.project.clone();
+
+                // Invalid settings keep the dialog open, showing the error
+                if self.project_settings.apply(&mut document.project).is_ok() {
+                    document.record_from(before);
+                    self.dialog.close();
+
+                    return self.update(Message::RefreshEditorContent);
+                }
+            }
+            Message::DialogYes
+                if self.dialog.action() == dialog::Action::ExternalChange =>
+            {
+                self.dialog.close();
+
+                let document = self.document();
+                if let Some(path) = document.path.clone() {
+                    let key = document.key();
+                    self.is_loading = true;
+
+                    return Task::perform(
+                        Project::from_path(path),
+                        move |result| Message::FileReloaded(key, result),
+                    );
+                }
+            }
+            Message::DialogNo
+                if self.dialog.action() == dialog::Action::ExternalChange =>
+            {
+                // Our version no longer matches the file, so it needs saving
+                self.document_mut().is_dirty = true;
+                self.dialog.close();
+            }
             Message::DialogYes => {
                 return if matches!(
                     self.dialog.action(),
                     dialog::Action::UnsavedChanges(_)
                 ) {
-                    self.is_loading = true;
-                    Task::perform(
-                        self.project
-                            .clone()
-                            .write_to_file(self.project_path.clone()),
-                        Message::FileSaved,
-                    )
-                    .chain(Task::done(Message::DialogNo))
+                    self.save(false).chain(Task::done(Message::DialogNo))
                 } else {
                     self.update(Message::CloseDialog)
                 };
             }
             Message::DialogNo => {
-                let mut task = Task::done(Message::CloseDialog);
-
                 if let dialog::Action::UnsavedChanges(unsaved_changes) =
                     self.dialog.action()
                 {
+                    self.dialog.close();
+
                     match unsaved_changes {
-                        UnsavedChanges::New => {
-                            self.is_dirty = false;
-                            self.project = Project::new();
-                            self.project_path = None;
-                            self.editor_content = text_editor::Content::new();
-                        }
-                        UnsavedChanges::Open => {
-                            self.is_dirty = false;
-                            self.is_loading = true;
-                            task = Task::perform(
-                                Project::from_file(),
-                                Message::FileOpened,
-                            )
-                            .chain(task);
+                        UnsavedChanges::Close => {
+                            self.close_document(self.active);
+
+                            return self.refresh_autosave().chain(
+                                self.update(Message::RefreshEditorContent),
+                            );
                         }
                         UnsavedChanges::Exit => {
-                            return self.update(Message::CloseApp);
+                            // Whatever wasn't saved is given up on
+                            self.document_mut().is_dirty = false;
+
+                            return self.exit_next_dirty();
                         }
                     }
                 }
 
-                return task;
+                return Task::done(Message::CloseDialog);
             }
             Message::DialogCancel => return Task::done(Message::CloseDialog),
             Message::NewFile => {
+                self.open_document(Document::default());
+                self.editor_content = text_editor::Content::new();
+            }
+            Message::OpenProjectSettings => {
+                self.project_settings =
+                    ProjectSettings::new(&self.document().project);
+                self.dialog = Dialog::project_settings();
+            }
+            Message::ProjectSettingsChanged(change) => {
+                self.project_settings.update(change);
+            }
+            Message::TemplatesLoaded(templates) => self.templates = templates,
+            Message::NewFromTemplate(template) => {
                 if !self.is_loading {
-                    if !self.is_dirty {
-                        self.project = Project::new();
-                        self.project_path = None;
-                        self.editor_content = text_editor::Content::new();
-                    } else {
-                        self.dialog = Dialog::unsaved_changes(
-                            "You have unsaved changes. Do you want to save them before creating a new project?",
-                            UnsavedChanges::New,
-                        );
+                    self.is_loading = true;
+
+                    return Task::perform(
+                        template.instantiate(),
+                        Message::TemplateInstantiated,
+                    );
+                }
+            }
+            Message::TemplateInstantiated(result) => {
+                self.is_loading = false;
+
+                match result {
+                    Ok(project) => {
+                        let mut document = Document::new(project, None);
+                        // Nothing of it has been saved yet
+                        document.is_dirty = true;
+                        self.open_document(document);
+
+                        return self.update(Message::RefreshEditorContent);
                     }
+                    Err(error) => self.dialog = Dialog::error(error),
                 }
             }
             Message::OpenFile => {
                 if !self.is_loading {
-                    if !self.is_dirty {
-                        self.is_loading = true;
+                    self.is_loading = true;
+
+                    return Task::perform(
+                        Project::from_file(),
+                        Message::FileOpened,
+                    );
+                }
+            }
+            Message::OpenRecent(path) => {
+                if !self.is_loading {
+                    if !path.is_file() {
+                        self.config.remove_recent_project(&path);
+                        self.dialog = Dialog::warning(format!(
+                            "The file {} does not exist anymore, so it was removed from the recent projects.",
+                            path.to_string_lossy()
+                        ));
 
                         return Task::perform(
-                            Project::from_file(),
-                            Message::FileOpened,
-                        );
-                    } else {
-                        self.dialog = Dialog::unsaved_changes(
-                            "You have unsaved changes. Do you want to save them before opening another project?",
-                            UnsavedChanges::Open,
+                            self.config.clone().save(),
+                            Message::ConfigWrite,
                         );
                     }
+
+                    let path = path.canonicalize().unwrap_or(path);
+                    if let Some(index) = self
+                        .documents
+                        .iter()
+                        .position(|document| document.is_at(&path))
+                    {
+                        return self.update(Message::SelectDocument(index));
+                    }
+
+                    self.is_loading = true;
+
+                    return Task::perform(
+                        Project::from_path(path),
+                        Message::FileOpened,
+                    );
                 }
             }
             Message::FileOpened(result) => {
                 self.is_loading = false;
-                self.is_dirty = false;
 
                 match result {
-                    Ok((path, project)) => {
-                        self.project = project;
-                        self.project_path =
-                            Some(path.canonicalize().unwrap_or(path));
+                    Ok((path, hash, project)) => {
+                        let path = path.canonicalize().unwrap_or(path);
+
+                        // Opening it again would lose the changes in its tab
+                        if let Some(index) = self
+                            .documents
+                            .iter()
+                            .position(|document| document.is_at(&path))
+                        {
+                            return self.update(Message::SelectDocument(index));
+                        }
+
+                        let mut document = Document::new(project, Some(path));
+                        document.file_hash = Some(hash);
+
+                        let mut warnings = Vec::new();
+                        let missing = asset::missing(
+                            document.project.referenced_assets(),
+                            document.path.as_deref(),
+                        );
+                        if !missing.is_empty() {
+                            warnings.push(format!(
+                                "The following assets could not be found:\n{}",
+                                missing
+                                    .iter()
+                                    .map(|path| format!(
+                                        "  {}",
+                                        path.to_string_lossy()
+                                    ))
+                                    .collect::<Vec<_>>()
+                                    .join("\n")
+                            ));
+                        }
+                        if let Some(theme) = document.project.unknown_theme() {
+                            warnings.push(format!(
+                                "The theme \"{theme}\" doesn't exist, so the default theme is used instead."
+                            ));
+                        }
+                        if !warnings.is_empty() {
+                            self.dialog =
+                                Dialog::warning(warnings.join("\n\n"));
+                        }
+
+                        self.open_document(document);
 
                         return Task::done(
-                            ConfigChangeType::LastProject.into(),
+                            ConfigChangeType::RecentProjects.into(),
                         )
-                        .chain(Task::done(Message::RefreshEditorContent));
+                        .chain(Task::done(Message::RefreshEditorContent))
+                        .chain(self.load_fonts());
                     }
                     Err(error) => self.dialog = Dialog::error(error),
                 };
             }
             Message::SaveFile => {
                 if !self.is_loading {
-                    self.is_loading = true;
+                    return self.save(false);
+                }
+            }
+            Message::SaveFileAs => {
+                if !self.is_loading {
+                    return self.save(true);
+                }
+            }
+            Message::FileSaved(key, result) => {
+                self.is_loading = false;
+
+                match result {
+                    Ok((path, hash)) => {
+                        self.config.add_recent_project(&path);
+
+                        if let Some(document) = self.find_document(key) {
+                            document.saved_to(path, hash);
+                        }
+
+                        return self.refresh_autosave().chain(Task::perform(
+                            self.config.clone().save(),
+                            Message::ConfigWrite,
+                        ));
+                    }
+                    Err(error) => self.dialog = Dialog::error(error),
+                }
+            }
+            Message::FileReloaded(key, result) => {
+                self.is_loading = false;
+
+                match result {
+                    Ok((_, hash, project)) => {
+                        if let Some(document) = self.find_document(key) {
+                            document.reload(project, hash);
+                        }
+
+                        return self
+                            .refresh_autosave()
+                            .chain(self.update(Message::RefreshEditorContent))
+                            .chain(self.load_fonts());
+                    }
+                    Err(error) => self.dialog = Dialog::error(error),
+                }
+            }
+            Message::SelectDocument(index) => {
+                if index < self.documents.len() {
+                    self.active = index;
+                    self.override_draft = None;
+
+                    return self
+                        .update(Message::RefreshEditorContent)
+                        .chain(self.load_fonts());
+                }
+            }
+            Message::CloseDocument(index) => {
+                if index >= self.documents.len() || self.dialog.is_open() {
+                    return Task::none();
+                }
+
+                if self.documents[index].is_dirty {
+                    self.active = index;
+                    self.dialog = Dialog::unsaved_changes(
+                        format!(
+                            "{} has unsaved changes. Do you want to save them before closing it?",
+                            self.document().name()
+                        ),
+                        UnsavedChanges::Close,
+                    );
+                } else {
+                    self.close_document(index);
+                }
+
+                return self.update(Message::RefreshEditorContent);
+            }
+            Message::Undo => {
+                if self.document_mut().undo() {
+                    return self.update(Message::RefreshEditorContent);
+                }
+            }
+            Message::Redo => {
+                if self.document_mut().redo() {
+                    return self.update(Message::RefreshEditorContent);
+                }
+            }
+            Message::CheckProjectFile => {
+                let document = self.document();
+
+                if let Some(path) = document.path.clone()
+                    && !self.is_loading
+                    && !self.dialog.is_open()
+                {
+                    let key = document.key();
+                    let known = document.file_hash;
 
                     return Task::perform(
-                        self.project
-                            .clone()
-                            .write_to_file(self.project_path.clone()),
-                        Message::FileSaved,
+                        types::project::file_hash(path),
+                        move |hash| {
+                            Message::ProjectFileChecked(key, known, hash)
+                        },
                     );
                 }
             }
-            Message::SaveFileAs => {
-                if !self.is_loading {
-                    self.is_loading = true;
+            Message::ProjectFileChecked(key, known, hash) => {
+                // A save or load started or finished in the meantime, or
+                // another tab was selected, so the result is stale
+                if self.is_loading
+                    || self.dialog.is_open()
+                    || self.document().key() != key
+                    || self.document().file_hash != known
+                {
+                    return Task::none();
+                }
 
+                let document = &mut self.documents[self.active];
+                match (document.file_hash, hash) {
+                    (None, _) => document.file_hash = hash,
+                    (Some(known), Some(hash)) if known != hash => {
+                        document.file_hash = Some(hash);
+                        self.dialog = Dialog::external_change(
+                            "The project file was changed outside of iced Builder. Do you want to reload it, or keep your version and overwrite it on the next save?",
+                        );
+                    }
+                    _ => {}
+                }
+            }
+            Message::ShowExternalDiff => {
+                let document = self.document();
+
+                if let Some(path) = document.path.clone() {
                     return Task::perform(
-                        self.project.clone().write_to_file(None),
-                        Message::FileSaved,
+                        document.project.clone().diff_with_file(path),
+                        Message::ExternalDiff,
                     );
                 }
             }
-            Message::FileSaved(result) => {
+            Message::ExternalDiff(result) => match result {
+                Ok(diff) => {
+                    self.dialog = Dialog::external_change(format!(
+                        "Lines starting with - are only in the file on disk, lines starting with + only in your version.\n\n{diff}"
+                    ));
+                }
+                Err(error) => self.dialog = Dialog::error(error),
+            },
+            Message::Autosave => {
+                if !self.is_loading
+                    && self.documents.iter().any(|document| document.is_dirty)
+                {
+                    return self.refresh_autosave();
+                }
+            }
+            Message::Autosaved(result) => {
+                self.is_autosaving = false;
+
+                match result {
+                    Ok(()) => self.autosave_failed = false,
+                    // Shown once per streak of failures, and never over
+                    // another dialog
+                    Err(error)
+                        if !self.autosave_failed && !self.dialog.is_open() =>
+                    {
+                        self.autosave_failed = true;
+                        self.dialog = Dialog::error(error);
+                    }
+                    Err(_) => {}
+                }
+
+                if self.is_closing {
+                    return self.update(Message::CloseApp);
+                }
+                if self.autosave_is_stale {
+                    self.autosave_is_stale = false;
+
+                    return self.refresh_autosave();
+                }
+            }
+            Message::Recovered(result) => {
                 self.is_loading = false;
+                self.dialog.close();
 
                 match result {
-                    Ok(path) => {
-                        self.project_path = Some(path);
-                        self.is_dirty = false;
+                    Ok(recovered) => {
+                        let mut fonts = Vec::new();
+                        for (path, project) in recovered {
+                            let mut document = Document::new(project, path);
+                            document.is_dirty = true;
+                            self.open_document(document);
+                            fonts.push(self.load_fonts());
+                        }
 
+                        // Compare against whatever is on disk from now on
                         return self
-                            .update(ConfigChangeType::LastProject.into());
+                            .update(Message::RefreshEditorContent)
+                            .chain(Task::done(Message::CheckProjectFile))
+                            .chain(Task::batch(fonts));
                     }
                     Err(error) => self.dialog = Dialog::error(error),
                 }
             }
             Message::CloseApp => {
-                return window::get_latest().and_then(window::close);
+                // The autosave in flight would land after the removal
+                if self.is_autosaving {
+                    self.is_closing = true;
+
+                    return Task::none();
+                }
+
+                // Closing on purpose means there's nothing left to recover
+                return Task::future(Project::discard_autosave())
+                    .discard()
+                    .chain(window::get_latest().and_then(window::close));
             }
             Message::EscapePressed
                 if self.dialog.action() == dialog::Action::Close =>
@@ -443,14 +1466,7 @@ impl IcedBuilder {
             }
             Message::EscapePressed => {}
             Message::WindowEvent(window::Event::CloseRequested) => {
-                if self.is_dirty {
-                    self.dialog = Dialog::unsaved_changes(
-                        "You have unsaved changes. Do you want to save them before closing iced Builder?",
-                        UnsavedChanges::Exit,
-                    );
-                } else {
-                    return self.update(Message::CloseApp);
-                }
+                return self.exit_next_dirty();
             }
             Message::WindowEvent(_) => {}
         }
@@ -471,6 +1487,14 @@ impl IcedBuilder {
                         })
                     }
                     keyboard::Key::Character("n") => Some(Message::NewFile),
+                    keyboard::Key::Character("z") => {
+                        Some(if modifiers.shift() {
+                            Message::Redo
+                        } else {
+                            Message::Undo
+                        })
+                    }
+                    keyboard::Key::Character("y") => Some(Message::Redo),
                     _ => None,
                 }
             } else if key.as_ref()
@@ -488,16 +1512,65 @@ impl IcedBuilder {
         let system_theme =
             Theme::subscription().map(Message::SystemThemeChanged);
 
-        Subscription::batch([keyboard, window_events, system_theme])
+        let project_file = if self.document().path.is_some() {
+            iced::time::every(PROJECT_FILE_POLL_INTERVAL)
+                .map(|_| Message::CheckProjectFile)
+        } else {
+            Subscription::none()
+        };
+
+        let autosave = self
+            .config
+            .autosave_interval()
+            .map_or_else(Subscription::none, |interval| {
+                iced::time::every(interval).map(|_| Message::Autosave)
+            });
+
+        Subscription::batch([
+            keyboard,
+            window_events,
+            system_theme,
+            project_file,
+            autosave,
+        ])
     }
 
     fn view(&self) -> Element<'_, Message> {
-        let header = row![pick_list(
-            self.config.themes(),
-            Some(self.theme.target()),
-            |theme| Message::SwitchTheme(theme.into())
-        )]
-        .width(200);
+        let document = self.document();
+        let project = &document.project;
+
+        let header = row![
+            pick_list(
+                self.templates.as_slice(),
+                None::<Template>,
+                Message::NewFromTemplate
+            )
+            .placeholder("New from template…"),
+            button("Undo")
+                .on_press_maybe(document.can_undo().then_some(Message::Undo)),
+            button("Redo")
+                .on_press_maybe(document.can_redo().then_some(Message::Redo)),
+            button("Project settings…").on_press(Message::OpenProjectSettings),
+            button("Export…").on_press(Message::ExportCode),
+            button("Export as Cargo project")
+                .on_press(Message::ExportCargoProject),
+            if self.runner.is_running() {
+                button("Stop").on_press(Message::StopRun)
+            } else {
+                button("Run").on_press(Message::Run)
+            },
+            button(text(format!("Problems ({})", document.problems.len())))
+                .on_press(Message::ShowProblems),
+            horizontal_space(),
+            pick_list(
+                self.config.themes(),
+                Some(self.theme.target()),
+                |theme| Message::SwitchTheme(theme.into())
+            )
+            .width(200),
+        ]
+        .spacing(10)
+        .padding([0, 10]);
         let pane_grid = pane_grid::PaneGrid::new(
             &self.pane_state,
             |id, pane, _is_maximized| {
@@ -505,15 +1578,55 @@ impl IcedBuilder {
                 match pane {
                     Panes::Designer => match &self.designer_page {
                         DesignerPane::DesignerView => designer_view::view(
-                            self.project.element_tree.as_ref(),
-                            self.project.get_theme(),
+                            project,
+                            ViewContext {
+                                project_path: document.path.clone(),
+                                components: project.components(),
+                                selected: document.selected.clone(),
+                                button_status: self.button_status,
+                                preview: self.preview,
+                            },
+                            Canvas {
+                                compared_themes: &self.compared_themes,
+                                viewport: &self.viewport,
+                                layout_debug: self.layout_debug,
+                                hovered_size: self
+                                    .hovered_size
+                                    .map(|(_, size)| size),
+                            },
+                            self.config.recent_projects(),
+                            &self.templates,
                             is_focused,
                         ),
                         DesignerPane::CodeView => {
                             code_view::view(&self.editor_content, is_focused)
                         }
                     },
-                    Panes::ElementList => element_list::view(is_focused),
+                    Panes::ElementList => {
+                        element_list::view(project.components(), is_focused)
+                    }
+                    Panes::Inspector => inspector::view(
+                        project,
+                        document.selected.as_ref(),
+                        &self.component_name,
+                        self.override_draft.as_ref(),
+                        self.button_status,
+                        is_focused,
+                    ),
+                    Panes::AssetLibrary => asset_library::view(
+                        &project.assets,
+                        &document.missing_assets,
+                        project.asset_mode,
+                        document.path.as_deref(),
+                        is_focused,
+                    ),
+                    Panes::RunLog => run_log::view(&self.runner, is_focused),
+                    Panes::Problems => problems::view(
+                        project,
+                        &document.problems,
+                        document.selected.as_ref(),
+                        is_focused,
+                    ),
                 }
             },
         )
@@ -526,14 +1639,20 @@ impl IcedBuilder {
 
         let base = Column::new()
             .push(header)
+            .push(document::tabs(&self.documents, self.active))
             .push(pane_grid)
             .spacing(5)
             .align_x(Alignment::Center)
             .width(Length::Fill);
 
-        let content = self
-            .dialog
-            .as_iced_dialog(container(base).height(Length::Fill));
+        let base = container(base).height(Length::Fill);
+        let content = if self.dialog.action() == dialog::Action::ProjectSettings
+        {
+            self.dialog
+                .as_iced_dialog_with(base, self.project_settings.view())
+        } else {
+            self.dialog.as_iced_dialog(base)
+        };
 
         Animation::new(&self.theme, content)
             .on_update(Message::SwitchTheme)
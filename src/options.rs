@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
-use iced::widget::text::LineHeight;
+use iced::widget::text::{LineHeight, Rich};
 #[allow(unused_imports)]
 use iced::widget::{Button, Column, Container, Image, Row, Svg, Text};
 use iced::{Alignment, ContentFit, Length, Padding, Pixels, Rotation};
@@ -40,43 +40,58 @@ impl<Message> ApplyOptions for Button<'_, Message> {
     }
 }
 
-impl ApplyOptions for Text<'_> {
-    fn apply_options(self, options: BTreeMap<String, Option<String>>) -> Self {
-        let mut text = self;
+/// Parses the option called `key`, skipping it if it's unset or invalid.
+fn parsed<T: Value>(
+    options: &BTreeMap<String, Option<String>>,
+    key: &str,
+) -> Option<T> {
+    T::from_str(options.get(key)?.as_deref()?).ok()
+}
 
-        if let Some(size) = options.get("size").expect("size key") {
-            let size = Pixels::from_str(size).unwrap();
+/// Applies the options shared by texts and rich texts.
+macro_rules! apply_text_options {
+    ($text:expr, $options:expr) => {{
+        let options = $options;
+        let mut text = $text;
+
+        if let Some(size) = parsed::<Pixels>(&options, "size") {
             text = text.size(size);
         }
 
-        if let Some(line_height) =
-            options.get("line_height").expect("line_height key")
+        if let Some(line_height) = parsed::<LineHeight>(&options, "line_height")
         {
-            let line_height = LineHeight::from_str(line_height).unwrap();
             text = text.line_height(line_height);
         }
 
-        if let Some(width) = options.get("width").expect("width key") {
-            let width = Length::from_str(width).unwrap();
+        if let Some(width) = parsed::<Length>(&options, "width") {
             text = text.width(width);
         }
 
-        if let Some(height) = options.get("height").expect("height key") {
-            let height = Length::from_str(height).unwrap();
+        if let Some(height) = parsed::<Length>(&options, "height") {
             text = text.height(height);
         }
 
-        if let Some(align_x) = options.get("align_x").expect("align_x key") {
-            let align_x = Alignment::from_str(align_x).unwrap();
+        if let Some(align_x) = parsed::<Alignment>(&options, "align_x") {
             text = text.align_x(align_x);
         }
 
-        if let Some(align_y) = options.get("align_y").expect("align_y key") {
-            let align_y = Alignment::from_str(align_y).unwrap();
+        if let Some(align_y) = parsed::<Alignment>(&options, "align_y") {
             text = text.align_y(align_y);
         }
 
         text
+    }};
+}
+
+impl ApplyOptions for Text<'_> {
+    fn apply_options(self, options: BTreeMap<String, Option<String>>) -> Self {
+        apply_text_options!(self, options)
+    }
+}
+
+impl<Link: Clone + 'static, Message> ApplyOptions for Rich<'_, Link, Message> {
+    fn apply_options(self, options: BTreeMap<String, Option<String>>) -> Self {
+        apply_text_options!(self, options)
     }
 }
 
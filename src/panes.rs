@@ -1,4 +1,9 @@
+pub mod asset_library;
 pub mod code_view;
 pub mod designer_view;
 pub mod element_list;
+pub mod inspector;
+pub mod problems;
+pub mod run_log;
 mod style;
+pub mod welcome;
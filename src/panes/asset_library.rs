@@ -0,0 +1,122 @@
+use std::path::{Path, PathBuf};
+
+use iced::widget::{
+    Row, button, center, checkbox, column, container, image, pane_grid, row,
+    scrollable, svg, text,
+};
+use iced::{Alignment, Length};
+use iced_drop::droppable;
+use iced_material::Theme;
+
+use super::style;
+use crate::types::asset::{self, AssetKind, AssetMode};
+use crate::types::{Element, Message};
+
+const THUMBNAIL_SIZE: f32 = 72.0;
+
+fn thumbnail<'a>(
+    index: usize,
+    path: &'a Path,
+    is_missing: bool,
+    project_path: Option<&Path>,
+) -> Element<'a, Message> {
+    let resolved = asset::resolve(path, project_path);
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let preview: Element<'a, Message> = if is_missing {
+        center(text("Missing").size(12)).into()
+    } else {
+        match AssetKind::from_path(path) {
+            Some(AssetKind::Svg) => svg(resolved).into(),
+            Some(AssetKind::Image) => image(resolved).into(),
+            Some(AssetKind::Font) => center(text("Aa").size(28)).into(),
+            None => center(text("?")).into(),
+        }
+    };
+
+    let preview = container(preview)
+        .width(THUMBNAIL_SIZE)
+        .height(THUMBNAIL_SIZE)
+        .padding(4)
+        .style(iced_material::container::surface_container_high);
+
+    let asset = path.to_path_buf();
+    let preview =
+        droppable(preview)
+            .drag_center(true)
+            .on_drop(move |point, rect| {
+                Message::DropAsset(asset.clone(), point, rect)
+            });
+
+    column![
+        preview,
+        text(name).size(12).width(THUMBNAIL_SIZE).center(),
+        button(text("Remove").size(12))
+            .on_press(Message::RemoveAsset(index))
+            .style(iced_material::button::text),
+    ]
+    .spacing(4)
+    .align_x(Alignment::Center)
+    .into()
+}
+
+pub fn view<'a>(
+    assets: &'a [PathBuf],
+    missing_assets: &[PathBuf],
+    asset_mode: AssetMode,
+    project_path: Option<&Path>,
+    is_focused: bool,
+) -> pane_grid::Content<'a, Message, Theme> {
+    let content: Element<'a, Message> = if assets.is_empty() {
+        center(text("No assets yet").size(14)).into()
+    } else {
+        scrollable(
+            Row::with_children(assets.iter().enumerate().map(
+                |(index, path)| {
+                    thumbnail(
+                        index,
+                        path,
+                        missing_assets.contains(path),
+                        project_path,
+                    )
+                },
+            ))
+            .spacing(10)
+            .padding(10)
+            .wrap(),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    };
+
+    let title_bar = pane_grid::TitleBar::new(text("Assets"))
+        .controls(pane_grid::Controls::new(
+            row![
+                checkbox("Embed in code", asset_mode == AssetMode::Embedded)
+                    .on_toggle(|embed| {
+                        Message::SetAssetMode(if embed {
+                            AssetMode::Embedded
+                        } else {
+                            AssetMode::Runtime
+                        })
+                    }),
+                button("Add…").on_press(Message::AddAssets),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        ))
+        .padding(10)
+        .style(style::title_bar);
+
+    pane_grid::Content::new(content)
+        .title_bar(title_bar)
+        .style(if is_focused {
+            style::pane_focused
+        } else {
+            style::pane_active
+        })
+}
@@ -6,16 +6,11 @@ use iced_custom_highlighter::{Highlight, Highlighter, Scope, Settings};
 use iced_material::Theme;
 
 use super::style;
-use crate::icon;
 use crate::types::{DesignerPane, Message};
+use crate::{appearance, icon};
 
-// TODO: implement a highlight style for the material theme
 fn highlight_style(theme: &Theme, scope: &Scope) -> Format<Font> {
-    let theme = if theme.is_dark() {
-        iced::Theme::SolarizedDark
-    } else {
-        iced::Theme::SolarizedLight
-    };
+    let theme = appearance::syntax_theme(theme);
 
     match scope {
         Scope::Custom { .. } | Scope::Other => Format {
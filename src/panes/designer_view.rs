@@ -1,39 +1,437 @@
-use iced::Length;
+use std::path::PathBuf;
+
 use iced::widget::{
-    button, center, container, pane_grid, responsive, text, themer,
+    Row, Space, button, center, column, container, horizontal_space, pane_grid,
+    pick_list, responsive, row, scrollable, text, text_input, themer,
 };
+use iced::{Alignment, Color, Length, Size};
 use iced_material::Theme;
 
-use super::style;
+use super::{style, welcome};
 use crate::icon;
-use crate::types::{DesignerPane, Message, RenderedElement};
+use crate::template::Template;
+use crate::types::style::ButtonStatus;
+use crate::types::viewport::{Preset, Viewport, Zoom};
+use crate::types::{
+    DesignerPane, Element, ElementName, Message, Project, ViewContext,
+};
+use crate::widget;
+
+/// The space around the previews, which are compared in different themes.
+const PREVIEW_SPACING: f32 = 20.0;
+/// The height of the theme names above compared previews, with their
+/// spacing.
+const LABEL_HEIGHT: f32 = 22.0;
+
+fn tabs(project: &Project) -> Element<'_, Message> {
+    if let Some(index) = project.editing_component() {
+        return row![
+            button(text(format!(
+                "Component: {}",
+                project.components()[index].name
+            )))
+            .style(iced_material::button::filled_tonal),
+            horizontal_space(),
+            button("Done").on_press(Message::StopEditingComponent),
+        ]
+        .spacing(10)
+        .padding([5, 10])
+        .align_y(Alignment::Center)
+        .into();
+    }
+
+    let tabs = Row::with_children(project.views().iter().enumerate().map(
+        |(index, view)| {
+            button(text(&view.name))
+                .on_press(Message::SelectView(index))
+                .style(if index == project.current_view() {
+                    iced_material::button::filled_tonal
+                } else {
+                    iced_material::button::text
+                })
+                .into()
+        },
+    ))
+    .spacing(5);
+
+    let current = &project.views()[project.current_view()];
+
+    row![
+        tabs,
+        button("+").on_press(Message::AddView),
+        horizontal_space(),
+        text_input("View name", &current.name)
+            .on_input(Message::RenameView)
+            .width(200),
+        button("Remove view").on_press_maybe(
+            (project.views().len() > 1).then_some(Message::RemoveView)
+        ),
+    ]
+    .spacing(10)
+    .padding([5, 10])
+    .align_y(Alignment::Center)
+    .into()
+}
+
+/// Switches the status buttons are drawn in, which is also the one their
+/// style is edited for.
+fn button_status(current: ButtonStatus) -> Element<'static, Message> {
+    let statuses = Row::with_children(ButtonStatus::ALL.iter().map(|status| {
+        button(text(status.to_string()).size(12))
+            .on_press(Message::PreviewButtonStatus(*status))
+            .style(if *status == current {
+                iced_material::button::filled_tonal
+            } else {
+                iced_material::button::text
+            })
+            .into()
+    }))
+    .spacing(5);
+
+    row![text("Buttons:").size(12), statuses]
+        .spacing(10)
+        .padding([0, 10])
+        .align_y(Alignment::Center)
+        .into()
+}
+
+/// Explains the colors of the layout overlay, with the size of the hovered
+/// element.
+fn layout_legend(hovered: Option<Size>) -> Element<'static, Message> {
+    let key = |color: Color, label: &'static str| {
+        row![
+            container(Space::new(12, 12)).style(move |_theme: &Theme| {
+                container::Style {
+                    background: Some(color.into()),
+                    ..container::Style::default()
+                }
+            }),
+            text(label).size(12),
+        ]
+        .spacing(5)
+        .align_y(Alignment::Center)
+    };
+
+    row![
+        key(widget::layout_debug::BOUNDS, "Bounds"),
+        key(widget::layout_debug::PADDING, "Padding"),
+        key(widget::layout_debug::SPACING, "Spacing"),
+        key(widget::layout_debug::COLLAPSED, "Zero size"),
+        horizontal_space(),
+        text(match hovered {
+            Some(size) =>
+                format!("Hovered: {:.1} × {:.1}", size.width, size.height),
+            None => String::from("Hover an element to see its size"),
+        })
+        .size(12),
+    ]
+    .spacing(15)
+    .padding([0, 10])
+    .align_y(Alignment::Center)
+    .into()
+}
+
+/// Picks the window size the view is previewed at and its zoom.
+fn viewport_controls(viewport: &Viewport) -> Element<'_, Message> {
+    let mut controls = row![
+        text("Window:").size(12),
+        pick_list(Preset::ALL, Some(viewport.preset), |preset| {
+            Message::SetViewport(Viewport {
+                preset,
+                ..viewport.clone()
+            })
+        })
+        .text_size(12),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+
+    if viewport.preset == Preset::Custom {
+        controls = controls.push(
+            row![
+                text_input("Width", &viewport.width)
+                    .on_input(|width| {
+                        Message::SetViewport(Viewport {
+                            width,
+                            ..viewport.clone()
+                        })
+                    })
+                    .size(12)
+                    .width(60),
+                text("×").size(12),
+                text_input("Height", &viewport.height)
+                    .on_input(|height| {
+                        Message::SetViewport(Viewport {
+                            height,
+                            ..viewport.clone()
+                        })
+                    })
+                    .size(12)
+                    .width(60),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+        );
+    }
+
+    let zoom = |label, message| {
+        button(text(label).size(12))
+            .on_press(message)
+            .style(iced_material::button::text)
+    };
+
+    controls
+        .push(zoom("−", Message::SetViewport(viewport.zoom_out())))
+        .push(
+            text(match viewport.percent() {
+                Some(percent) => format!("{percent}%"),
+                None => String::from("Fit"),
+            })
+            .size(12),
+        )
+        .push(zoom("+", Message::SetViewport(viewport.zoom_in())))
+        .push(
+            button(text("Fit").size(12))
+                .on_press(Message::SetViewport(Viewport {
+                    zoom: Zoom::Fit,
+                    ..viewport.clone()
+                }))
+                .style(if viewport.zoom == Zoom::Fit {
+                    iced_material::button::filled_tonal
+                } else {
+                    iced_material::button::text
+                }),
+        )
+        .into()
+}
+
+/// Picks the themes the view is compared in, starting from the project's.
+fn theme_comparison<'a>(
+    project: &Project,
+    compared: &'a [iced::Theme],
+) -> Element<'a, Message> {
+    let chosen = Row::with_children(compared.iter().enumerate().map(
+        |(index, theme)| {
+            let mut remaining = compared.to_vec();
+            let _ = remaining.remove(index);
+
+            // Comparing a single theme is the same as not comparing
+            if remaining.len() == 1 {
+                remaining.clear();
+            }
+
+            button(text(format!("{theme} ×")).size(12))
+                .on_press(Message::CompareThemes(remaining))
+                .style(iced_material::button::filled_tonal)
+                .into()
+        },
+    ))
+    .spacing(5);
+
+    let project_theme = project.get_theme();
+    let base = if compared.is_empty() {
+        vec![project_theme]
+    } else {
+        compared.to_vec()
+    };
+    let choices: Vec<iced::Theme> = iced::Theme::ALL
+        .iter()
+        .filter(|theme| !base.contains(theme))
+        .cloned()
+        .collect();
+
+    let mut comparison = row![
+        text("Themes:").size(12),
+        chosen,
+        pick_list(choices, None::<iced::Theme>, move |theme| {
+            let mut themes = base.clone();
+            themes.push(theme);
+
+            Message::CompareThemes(themes)
+        })
+        .placeholder("Compare with…")
+        .text_size(12),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+
+    if compared.is_empty() {
+        comparison = comparison.push(
+            button(text("Light and dark").size(12))
+                .on_press(Message::CompareThemes(vec![
+                    iced::Theme::Light,
+                    iced::Theme::Dark,
+                ]))
+                .style(iced_material::button::text),
+        );
+    }
+
+    comparison.into()
+}
+
+/// How the designer draws the view, besides what its elements need.
+#[derive(Debug, Clone, Copy)]
+pub struct Canvas<'a> {
+    /// The themes the view is compared in, or none to only use the
+    /// project's.
+    pub compared_themes: &'a [iced::Theme],
+    pub viewport: &'a Viewport,
+    /// Whether to draw the bounds, padding and spacing of every element.
+    pub layout_debug: bool,
+    pub hovered_size: Option<Size>,
+}
 
 pub fn view<'a>(
-    element_tree: Option<&'a RenderedElement>,
-    designer_theme: iced::Theme,
+    project: &'a Project,
+    context: ViewContext<'a>,
+    canvas: Canvas<'a>,
+    recent_projects: &'a [PathBuf],
+    templates: &'a [Template],
     is_focused: bool,
 ) -> pane_grid::Content<'a, Message, Theme> {
-    let el_tree: iced::Element<'a, Message> = match element_tree {
-        Some(tree) => responsive(|size| {
-            center(
-                container(tree.clone())
-                    .style(|theme| {
-                        container::background(theme.palette().background)
-                    })
-                    .height(size.height * 0.5)
-                    .width(size.height * 0.8),
-            )
+    let is_blank = project.views().len() == 1
+        && project.components().is_empty()
+        && context.project_path.is_none();
+    let has_buttons = project.element_tree().is_some_and(|tree| {
+        tree.any(&|name| matches!(name, ElementName::Button(_)))
+    });
+    let status = context.button_status;
+    let preview = context.preview;
+
+    let Canvas {
+        compared_themes,
+        viewport,
+        layout_debug,
+        hovered_size,
+    } = canvas;
+
+    let themes = if compared_themes.is_empty() {
+        vec![project.get_theme()]
+    } else {
+        compared_themes.to_vec()
+    };
+    let is_compared = themes.len() > 1;
+
+    let window = project
+        .window_size
+        .map(|window| Size::new(window.width, window.height));
+    let window_size = viewport.size(window);
+
+    let el_tree: Element<'a, Message> = match project.element_tree() {
+        Some(tree) => responsive(move |size| {
+            // Previews share the width, each fitting its part of it
+            let count = themes.len() as f32;
+            let label = if is_compared { LABEL_HEIGHT } else { 0.0 };
+            let scale = viewport.scale(
+                window,
+                Size::new(
+                    (size.width - PREVIEW_SPACING * (count + 1.0)) / count,
+                    size.height - PREVIEW_SPACING * 2.0 - label,
+                ),
+            );
+
+            let previews = Row::with_children(themes.iter().enumerate().map(
+                |(index, theme)| {
+                    let window = widget::scale(
+                        container(tree.view(&context))
+                            .style(|theme| {
+                                container::background(
+                                    theme.palette().background,
+                                )
+                            })
+                            .width(window_size.width)
+                            .height(window_size.height),
+                        window_size,
+                        scale,
+                    );
+
+                    let preview = themer(
+                        theme.clone(),
+                        if layout_debug {
+                            // The overlay sees the scaled layout, while hovered
+                            // sizes are reported as laid out
+                            widget::layout_debug(window, tree.paddings())
+                                .scale(scale)
+                                .on_hover(move |size| {
+                                    Message::LayoutHovered(
+                                        index,
+                                        size.map(|size| size * (1.0 / scale)),
+                                    )
+                                })
+                                .into()
+                        } else {
+                            iced::Element::from(window)
+                        },
+                    );
+
+                    if is_compared {
+                        column![text(theme.to_string()).size(12), preview]
+                            .spacing(5)
+                            .into()
+                    } else {
+                        preview.into()
+                    }
+                },
+            ))
+            .spacing(PREVIEW_SPACING)
+            .padding(PREVIEW_SPACING);
+
+            // Zoomed in previews can be larger than the designer
+            center(scrollable(previews).direction(
+                scrollable::Direction::Both {
+                    vertical: scrollable::Scrollbar::default(),
+                    horizontal: scrollable::Scrollbar::default(),
+                },
+            ))
             .into()
         })
         .into(),
-        None => center("Open a project or begin creating one").into(),
+        None if is_blank => welcome::view(recent_projects, templates),
+        None => {
+            center("Drag an element here to start building this view").into()
+        }
     };
 
-    let content = container(themer(designer_theme, el_tree))
+    let canvas = container(el_tree)
         .id("drop_zone")
         .height(Length::Fill)
         .width(Length::Fill);
 
+    let mut content = column![tabs(project)];
+    if has_buttons {
+        content = content.push(button_status(status));
+    }
+    if project.element_tree().is_some() {
+        content = content.push(
+            row![
+                button(text("Preview").size(12))
+                    .on_press(Message::SetPreview(!preview))
+                    .style(if preview {
+                        iced_material::button::filled_tonal
+                    } else {
+                        iced_material::button::text
+                    }),
+                button(text("Layout").size(12))
+                    .on_press(Message::SetLayoutDebug(!layout_debug))
+                    .style(if layout_debug {
+                        iced_material::button::filled_tonal
+                    } else {
+                        iced_material::button::text
+                    }),
+                viewport_controls(viewport),
+                horizontal_space(),
+                theme_comparison(project, compared_themes),
+            ]
+            .spacing(10)
+            .padding([0, 10])
+            .align_y(Alignment::Center),
+        );
+    }
+    if layout_debug && project.element_tree().is_some() {
+        content = content.push(layout_legend(hovered_size));
+    }
+    let content = content.push(canvas);
+
     let title_bar = pane_grid::TitleBar::new(text("Designer").center())
         .controls(pane_grid::Controls::dynamic(
             button("Switch to Code view")
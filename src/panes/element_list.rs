@@ -1,12 +1,15 @@
-use iced::widget::{Column, column, container, pane_grid, text};
+use iced::widget::{
+    Column, button, column, container, pane_grid, row, scrollable, text,
+};
 use iced::{Alignment, Length};
 use iced_drop::droppable;
 use iced_material::Theme;
 
 use super::style;
+use crate::types::project::View;
 use crate::types::{Element, ElementName, Message};
 
-fn items_list_view<'a>() -> Element<'a, Message> {
+fn items_list_view<'a>(components: &'a [View]) -> Element<'a, Message> {
     let mut column = Column::new()
         .spacing(20)
         .align_x(Alignment::Center)
@@ -22,14 +25,40 @@ fn items_list_view<'a>() -> Element<'a, Message> {
         );
     }
 
-    container(column)
+    if !components.is_empty() {
+        column = column.push(text("Components").size(14));
+    }
+
+    for (index, component) in components.iter().enumerate() {
+        let name = ElementName::Component(component.name.clone());
+
+        column = column.push(
+            row![
+                droppable(text(&component.name)).drag_center(true).on_drop(
+                    move |point, rect| {
+                        Message::DropNewElement(name.clone(), point, rect)
+                    }
+                ),
+                button(text("Edit").size(12))
+                    .on_press(Message::EditComponent(index))
+                    .style(iced_material::button::text),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        );
+    }
+
+    container(scrollable(column).width(Length::Fill))
         .width(Length::Fill)
         .height(Length::Fill)
         .into()
 }
 
-pub fn view<'a>(is_focused: bool) -> pane_grid::Content<'a, Message, Theme> {
-    let items_list = items_list_view();
+pub fn view<'a>(
+    components: &'a [View],
+    is_focused: bool,
+) -> pane_grid::Content<'a, Message, Theme> {
+    let items_list = items_list_view(components);
     let content = column![items_list]
         .align_x(Alignment::Center)
         .height(Length::Fill)
@@ -0,0 +1,869 @@
+use iced::advanced::widget::Id;
+use iced::theme::Palette;
+use iced::widget::{
+    Column, button, center, checkbox, column, pane_grid, pick_list, row,
+    scrollable, slider, text, text_input,
+};
+use iced::{Alignment, Length};
+use iced_material::Theme;
+
+use super::style;
+use crate::types::font::{ElementFont, Family, Stretch, Style, Weight};
+use crate::types::project::PaletteColor;
+use crate::types::span::Span;
+use crate::types::style::{
+    Appearance, Background, Border, ButtonStatus, ButtonVariant, ColorRef,
+    ElementStyle, Shadow, Styling,
+};
+use crate::types::{Element, ElementName, Message, Project, RenderedElement};
+use crate::widget;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FamilyChoice {
+    Default,
+    SansSerif,
+    Serif,
+    Monospace,
+    Named,
+}
+
+impl FamilyChoice {
+    const ALL: &'static [Self] = &[
+        Self::Default,
+        Self::SansSerif,
+        Self::Serif,
+        Self::Monospace,
+        Self::Named,
+    ];
+
+    fn of(family: Option<&Family>) -> Self {
+        match family {
+            None => Self::Default,
+            Some(Family::SansSerif) => Self::SansSerif,
+            Some(Family::Serif) => Self::Serif,
+            Some(Family::Monospace) => Self::Monospace,
+            Some(Family::Name(_)) => Self::Named,
+        }
+    }
+
+    fn family(self) -> Option<Family> {
+        match self {
+            Self::Default => None,
+            Self::SansSerif => Some(Family::SansSerif),
+            Self::Serif => Some(Family::Serif),
+            Self::Monospace => Some(Family::Monospace),
+            Self::Named => Some(Family::Name(String::new())),
+        }
+    }
+}
+
+impl std::fmt::Display for FamilyChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Default => "Default",
+            Self::SansSerif => "Sans serif",
+            Self::Serif => "Serif",
+            Self::Monospace => "Monospace",
+            Self::Named => "By name",
+        })
+    }
+}
+
+/// Edits the font of a text, button label or rich text span, with the
+/// shaping only set on whole texts.
+fn font_editor<'a>(
+    font: &ElementFont,
+    shaping: bool,
+    on_change: impl Fn(ElementFont) -> Message + Clone + 'a,
+) -> Element<'a, Message> {
+    let labeled = |label: &'a str, field: Element<'a, Message>| {
+        row![text(label).size(12).width(90), field]
+            .spacing(10)
+            .align_y(Alignment::Center)
+    };
+
+    let family = font.family.clone();
+    let current = font.clone();
+    let set = on_change.clone();
+    let mut editor = column![
+        text("Font"),
+        labeled(
+            "Family",
+            pick_list(
+                FamilyChoice::ALL,
+                Some(FamilyChoice::of(family.as_ref())),
+                move |choice| {
+                    set(ElementFont {
+                        family: choice.family(),
+                        ..current.clone()
+                    })
+                },
+            )
+            .into(),
+        ),
+    ]
+    .spacing(10);
+
+    if let Some(Family::Name(name)) = family {
+        let current = font.clone();
+        let set = on_change.clone();
+
+        editor = editor.push(
+            text_input("Family name", &name)
+                .on_input(move |name| {
+                    set(ElementFont {
+                        family: Some(Family::Name(name)),
+                        ..current.clone()
+                    })
+                })
+                .on_submit(Message::CommitFonts),
+        );
+    }
+
+    let current = font.clone();
+    let set = on_change.clone();
+    editor = editor.push(labeled(
+        "Weight",
+        pick_list(Weight::ALL, Some(font.weight), move |weight| {
+            set(ElementFont {
+                weight,
+                ..current.clone()
+            })
+        })
+        .into(),
+    ));
+
+    let current = font.clone();
+    let set = on_change.clone();
+    editor = editor.push(labeled(
+        "Style",
+        pick_list(Style::ALL, Some(font.style), move |style| {
+            set(ElementFont {
+                style,
+                ..current.clone()
+            })
+        })
+        .into(),
+    ));
+
+    let current = font.clone();
+    let set = on_change.clone();
+    editor = editor.push(labeled(
+        "Stretch",
+        pick_list(Stretch::ALL, Some(font.stretch), move |stretch| {
+            set(ElementFont {
+                stretch,
+                ..current.clone()
+            })
+        })
+        .into(),
+    ));
+
+    if shaping {
+        let current = font.clone();
+
+        editor = editor.push(
+            checkbox("Advanced shaping", font.advanced_shaping).on_toggle(
+                move |advanced_shaping| {
+                    on_change(ElementFont {
+                        advanced_shaping,
+                        ..current.clone()
+                    })
+                },
+            ),
+        );
+    }
+
+    editor.into()
+}
+
+/// Edits the segments of a rich text, each with its own color, size, font
+/// and link.
+fn span_editor<'a>(spans: &[Span]) -> Element<'a, Message> {
+    let segments = spans.iter().enumerate().map(|(index, span)| {
+        let mut removed = spans.to_vec();
+        let _ = removed.remove(index);
+
+        // Every control sends the whole list with one segment changed
+        let all = spans.to_vec();
+        let set = move |span: Span| {
+            let mut spans = all.clone();
+            spans[index] = span;
+
+            Message::SetSpans(spans)
+        };
+
+        let current = span.clone();
+        let set_text = set.clone();
+        let mut segment = column![
+            row![
+                text(format!("Segment {}", index + 1)),
+                button("Remove").on_press(Message::SetSpans(removed)),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            text_input("Text", &span.text).on_input(move |text| {
+                set_text(Span {
+                    text,
+                    ..current.clone()
+                })
+            }),
+        ]
+        .spacing(10);
+
+        let current = span.clone();
+        let set_color = set.clone();
+        segment = segment.push(
+            checkbox("Color", span.color.is_some()).on_toggle(move |color| {
+                set_color(Span {
+                    color: color.then_some(iced::Color::BLACK),
+                    ..current.clone()
+                })
+            }),
+        );
+
+        if let Some(color) = span.color {
+            let current = span.clone();
+            let set_color = set.clone();
+
+            segment = segment.push(
+                row![
+                    widget::color::swatch(color),
+                    widget::color::sliders(
+                        color,
+                        move |color| {
+                            Message::Sliding(Box::new(set_color(Span {
+                                color: Some(color),
+                                ..current.clone()
+                            })))
+                        },
+                        Some(Message::SliderReleased),
+                    ),
+                ]
+                .spacing(10),
+            );
+        }
+
+        let current = span.clone();
+        let set_size = set.clone();
+        segment = segment.push(
+            checkbox("Custom size", span.size.is_some()).on_toggle(
+                move |size| {
+                    set_size(Span {
+                        size: size.then_some(16.0),
+                        ..current.clone()
+                    })
+                },
+            ),
+        );
+
+        if let Some(size) = span.size {
+            let current = span.clone();
+            let set_size = set.clone();
+
+            segment =
+                segment.push(number("Size", 8.0..=72.0, size, move |size| {
+                    set_size(Span {
+                        size: Some(size),
+                        ..current.clone()
+                    })
+                }));
+        }
+
+        let current = span.clone();
+        let set_font = set.clone();
+        segment = segment.push(font_editor(&span.font, false, move |font| {
+            set_font(Span {
+                font,
+                ..current.clone()
+            })
+        }));
+
+        let current = span.clone();
+        let segment: Element<'a, Message> = segment
+            .push(
+                text_input(
+                    "Link (optional)",
+                    span.link.as_deref().unwrap_or(""),
+                )
+                .on_input(move |link| {
+                    set(Span {
+                        link: (!link.is_empty()).then_some(link),
+                        ..current.clone()
+                    })
+                }),
+            )
+            .into();
+
+        segment
+    });
+
+    let mut added = spans.to_vec();
+    added.push(Span::new(""));
+
+    column![
+        text("Segments"),
+        Column::with_children(segments).spacing(20),
+        button("Add segment").on_press(Message::SetSpans(added)),
+    ]
+    .spacing(10)
+    .into()
+}
+
+/// Where a color of the style editor comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorChoice {
+    Theme,
+    Palette(PaletteColor),
+    Custom,
+}
+
+impl ColorChoice {
+    fn all(optional: bool) -> Vec<Self> {
+        optional
+            .then_some(Self::Theme)
+            .into_iter()
+            .chain(PaletteColor::ALL.iter().copied().map(Self::Palette))
+            .chain([Self::Custom])
+            .collect()
+    }
+}
+
+impl std::fmt::Display for ColorChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Theme => f.write_str("Theme default"),
+            Self::Palette(color) => write!(f, "{color}"),
+            Self::Custom => f.write_str("Custom"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BackgroundKind {
+    None,
+    Color,
+    Gradient,
+}
+
+impl BackgroundKind {
+    const ALL: &'static [Self] = &[Self::None, Self::Color, Self::Gradient];
+
+    fn of(background: Option<Background>) -> Self {
+        match background {
+            None => Self::None,
+            Some(Background::Color(_)) => Self::Color,
+            Some(Background::Gradient { .. }) => Self::Gradient,
+        }
+    }
+
+    fn apply(self, background: Option<Background>) -> Option<Background> {
+        let background = background.unwrap_or_default();
+
+        match self {
+            Self::None => None,
+            Self::Color => Some(background.color()),
+            Self::Gradient => Some(background.gradient()),
+        }
+    }
+}
+
+impl std::fmt::Display for BackgroundKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::None => "None",
+            Self::Color => "Color",
+            Self::Gradient => "Gradient",
+        })
+    }
+}
+
+/// Picks a palette color or a custom one, which starts from the color
+/// currently shown.
+fn color_field<'a>(
+    label: &'a str,
+    color: Option<ColorRef>,
+    optional: bool,
+    palette: Palette,
+    on_change: impl Fn(Option<ColorRef>) -> Message + Clone + 'a,
+) -> Element<'a, Message> {
+    let choice = match color {
+        None => ColorChoice::Theme,
+        Some(ColorRef::Palette(color)) => ColorChoice::Palette(color),
+        Some(ColorRef::Literal(_)) => ColorChoice::Custom,
+    };
+    let shown = color.map(|color| color.resolve(palette));
+
+    let on_select = on_change.clone();
+    let mut field = column![
+        row![
+            text(label).size(12).width(90),
+            widget::color::swatch(shown.unwrap_or(palette.text)),
+            pick_list(
+                ColorChoice::all(optional),
+                Some(choice),
+                move |choice| {
+                    on_select(match choice {
+                        ColorChoice::Theme => None,
+                        ColorChoice::Palette(color) => {
+                            Some(ColorRef::Palette(color))
+                        }
+                        ColorChoice::Custom => Some(ColorRef::Literal(
+                            shown.unwrap_or(palette.text),
+                        )),
+                    })
+                }
+            ),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center)
+    ]
+    .spacing(5);
+
+    if let Some(ColorRef::Literal(color)) = color {
+        field = field.push(widget::color::sliders(
+            color,
+            move |color| {
+                Message::Sliding(Box::new(on_change(Some(ColorRef::Literal(
+                    color,
+                )))))
+            },
+            Some(Message::SliderReleased),
+        ));
+    }
+
+    field.into()
+}
+
+fn number<'a>(
+    label: &'a str,
+    range: std::ops::RangeInclusive<f32>,
+    value: f32,
+    on_change: impl Fn(f32) -> Message + 'a,
+) -> Element<'a, Message> {
+    // A drag is one edit, ended by its release
+    column![
+        text(format!("{label}: {value}")).size(12),
+        slider(range, value, move |value| {
+            Message::Sliding(Box::new(on_change(value)))
+        })
+        .step(0.5)
+        .on_release(Message::SliderReleased),
+    ]
+    .spacing(4)
+    .into()
+}
+
+/// Edits colors, border and shadow, or only the text color of texts.
+fn appearance_editor<'a>(
+    style: Appearance,
+    styling: Styling,
+    palette: Palette,
+    on_change: impl Fn(Appearance) -> Message + Copy + 'a,
+) -> Element<'a, Message> {
+    let mut editor = column![color_field(
+        "Text color",
+        style.text_color,
+        true,
+        palette,
+        move |text_color| {
+            on_change(Appearance {
+                text_color,
+                ..style
+            })
+        }
+    )]
+    .spacing(10);
+
+    if styling == Styling::Text {
+        return editor.into();
+    }
+
+    editor = editor.push(
+        row![
+            text("Background").size(12).width(90),
+            pick_list(
+                BackgroundKind::ALL,
+                Some(BackgroundKind::of(style.background)),
+                move |kind| on_change(Appearance {
+                    background: kind.apply(style.background),
+                    ..style
+                }),
+            ),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+    );
+
+    let set_background = move |background| {
+        on_change(Appearance {
+            background: Some(background),
+            ..style
+        })
+    };
+
+    match style.background {
+        None => {}
+        Some(Background::Color(color)) => {
+            editor = editor.push(color_field(
+                "Color",
+                Some(color),
+                false,
+                palette,
+                move |new| {
+                    set_background(Background::Color(new.unwrap_or(color)))
+                },
+            ));
+        }
+        Some(Background::Gradient { angle, start, end }) => {
+            editor = editor
+                .push(number("Angle", 0.0..=360.0, angle, move |angle| {
+                    set_background(Background::Gradient { angle, start, end })
+                }))
+                .push(color_field(
+                    "Start",
+                    Some(start),
+                    false,
+                    palette,
+                    move |new| {
+                        set_background(Background::Gradient {
+                            angle,
+                            start: new.unwrap_or(start),
+                            end,
+                        })
+                    },
+                ))
+                .push(color_field(
+                    "End",
+                    Some(end),
+                    false,
+                    palette,
+                    move |new| {
+                        set_background(Background::Gradient {
+                            angle,
+                            start,
+                            end: new.unwrap_or(end),
+                        })
+                    },
+                ));
+        }
+    }
+
+    editor = editor.push(checkbox("Border", style.border.is_some()).on_toggle(
+        move |border| {
+            on_change(Appearance {
+                border: border.then(Border::default),
+                ..style
+            })
+        },
+    ));
+
+    if let Some(border) = style.border {
+        let set_border = move |border| {
+            on_change(Appearance {
+                border: Some(border),
+                ..style
+            })
+        };
+
+        editor = editor
+            .push(number("Width", 0.0..=10.0, border.width, move |width| {
+                set_border(Border { width, ..border })
+            }))
+            .push(number("Radius", 0.0..=40.0, border.radius, move |radius| {
+                set_border(Border { radius, ..border })
+            }))
+            .push(color_field(
+                "Color",
+                Some(border.color),
+                false,
+                palette,
+                move |color| {
+                    set_border(Border {
+                        color: color.unwrap_or(border.color),
+                        ..border
+                    })
+                },
+            ));
+    }
+
+    editor = editor.push(checkbox("Shadow", style.shadow.is_some()).on_toggle(
+        move |shadow| {
+            on_change(Appearance {
+                shadow: shadow.then(Shadow::default),
+                ..style
+            })
+        },
+    ));
+
+    if let Some(shadow) = style.shadow {
+        let set_shadow = move |shadow| {
+            on_change(Appearance {
+                shadow: Some(shadow),
+                ..style
+            })
+        };
+
+        editor = editor
+            .push(color_field(
+                "Color",
+                Some(shadow.color),
+                false,
+                palette,
+                move |color| {
+                    set_shadow(Shadow {
+                        color: color.unwrap_or(shadow.color),
+                        ..shadow
+                    })
+                },
+            ))
+            .push(number(
+                "Offset x",
+                -20.0..=20.0,
+                shadow.offset_x,
+                move |offset_x| set_shadow(Shadow { offset_x, ..shadow }),
+            ))
+            .push(number(
+                "Offset y",
+                -20.0..=20.0,
+                shadow.offset_y,
+                move |offset_y| set_shadow(Shadow { offset_y, ..shadow }),
+            ))
+            .push(number("Blur", 0.0..=40.0, shadow.blur, move |blur| {
+                set_shadow(Shadow { blur, ..shadow })
+            }));
+    }
+
+    editor.into()
+}
+
+/// Lists what an instance can override, with the component's values as
+/// placeholders.
+fn overrides<'a>(
+    project: &'a Project,
+    instance: &'a RenderedElement,
+    name: &'a str,
+    draft: Option<&'a (String, String)>,
+) -> Element<'a, Message> {
+    let Some((index, component)) = project
+        .components()
+        .iter()
+        .enumerate()
+        .find(|(_, component)| component.name == name)
+    else {
+        return text(format!("The component {name} doesn't exist anymore"))
+            .into();
+    };
+
+    let slots = component
+        .element_tree
+        .iter()
+        .flat_map(RenderedElement::override_slots);
+
+    let fields = Column::with_children(slots.map(|slot| {
+        let draft = draft
+            .filter(|(key, _)| *key == slot.key)
+            .map(|(_, value)| value.clone());
+        let is_valid = draft.is_none();
+        let value = draft.unwrap_or_else(|| {
+            instance
+                .overrides()
+                .get(&slot.key)
+                .cloned()
+                .flatten()
+                .unwrap_or_default()
+        });
+        let key = slot.key.clone();
+
+        let mut field = column![
+            text(slot.label).size(12),
+            text_input(&slot.value, &value).on_input(move |value| {
+                Message::SetOverride(key.clone(), value)
+            }),
+        ]
+        .spacing(4);
+
+        if !is_valid {
+            field = field.push(
+                text("Not a valid value, the last valid one is used")
+                    .size(12)
+                    .style(|theme: &Theme| text::Style {
+                        color: Some(theme.colors().error.color),
+                    }),
+            );
+        }
+
+        field.into()
+    }))
+    .spacing(10);
+
+    column![
+        text(format!("Instance of {name}")),
+        button("Edit component").on_press(Message::EditComponent(index)),
+        fields,
+    ]
+    .spacing(15)
+    .into()
+}
+
+/// Edits the style of an element, with buttons styled per status.
+fn style_editor<'a>(
+    element: &RenderedElement,
+    palette: Palette,
+    status: ButtonStatus,
+) -> Element<'a, Message> {
+    let style = *element.style();
+    let styling = Styling::of(element.name());
+
+    match styling {
+        Styling::None => text(format!("A {} can't be styled", element.name()))
+            .size(12)
+            .into(),
+        Styling::Text | Styling::Box => column![
+            text("Style"),
+            appearance_editor(style.base, styling, palette, move |base| {
+                Message::SetStyle(ElementStyle { base, ..style })
+            }),
+        ]
+        .spacing(10)
+        .into(),
+        Styling::Button => column![
+            text("Style"),
+            row![
+                text("Variant").size(12).width(90),
+                pick_list(
+                    ButtonVariant::ALL,
+                    Some(style.variant),
+                    move |variant| {
+                        Message::SetStyle(ElementStyle { variant, ..style })
+                    }
+                ),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            text(match status {
+                ButtonStatus::Active => String::from(
+                    "Editing the base style, used in every status",
+                ),
+                status => format!(
+                    "Editing the {} overrides",
+                    status.to_string().to_lowercase()
+                ),
+            })
+            .size(12),
+            appearance_editor(
+                style.layer(status),
+                styling,
+                palette,
+                move |layer| {
+                    Message::SetStyle(style.with_layer(status, layer))
+                },
+            ),
+        ]
+        .spacing(10)
+        .into(),
+    }
+}
+
+/// The editors of the style and, for texts and buttons, the font of an
+/// element, the segments of a rich text or the description of an image.
+fn editors<'a>(
+    element: &RenderedElement,
+    palette: Palette,
+    status: ButtonStatus,
+) -> Element<'a, Message> {
+    let mut editors =
+        column![style_editor(element, palette, status)].spacing(20);
+
+    match element.name() {
+        ElementName::Text(_) | ElementName::Button(_) => {
+            editors = editors.push(font_editor(
+                element.font(),
+                true,
+                Message::SetFont,
+            ));
+        }
+        ElementName::RichText(spans) => {
+            editors = editors.push(span_editor(spans));
+        }
+        ElementName::Image(_) | ElementName::Svg(_) => {
+            editors = editors.push(
+                column![
+                    text("Description"),
+                    text_input(
+                        "What it shows, for screen readers",
+                        element.description().unwrap_or(""),
+                    )
+                    .on_input(Message::SetDescription),
+                ]
+                .spacing(10),
+            );
+        }
+        _ => {}
+    }
+
+    editors.into()
+}
+
+pub fn view<'a>(
+    project: &'a Project,
+    selected: Option<&Id>,
+    component_name: &'a str,
+    override_draft: Option<&'a (String, String)>,
+    button_status: ButtonStatus,
+    is_focused: bool,
+) -> pane_grid::Content<'a, Message, Theme> {
+    let element = selected.and_then(|id| {
+        project
+            .element_tree()
+            .and_then(|element_tree| element_tree.find(id))
+    });
+
+    let palette = project.get_theme().palette();
+
+    let content: Element<'a, Message> = match element {
+        None => center(text("Click an element to select it").size(14)).into(),
+        Some(element) => match element.component_name() {
+            Some(name) => overrides(project, element, name, override_draft),
+            None if project.editing_component().is_some() => column![
+                text(format!("Selected: {}", element.name())),
+                editors(element, palette, button_status),
+            ]
+            .spacing(15)
+            .into(),
+            None => column![
+                text(format!("Selected: {}", element.name())),
+                text_input("Component name", component_name)
+                    .on_input(Message::ComponentNameChanged)
+                    .on_submit(Message::PromoteToComponent),
+                button("Make component").on_press_maybe(
+                    (!component_name.trim().is_empty())
+                        .then_some(Message::PromoteToComponent)
+                ),
+                editors(element, palette, button_status),
+            ]
+            .spacing(10)
+            .into(),
+        },
+    };
+
+    let content = scrollable(
+        column![content]
+            .padding(10)
+            .align_x(Alignment::Start)
+            .width(Length::Fill),
+    )
+    .height(Length::Fill);
+
+    let title_bar = pane_grid::TitleBar::new(text("Inspector"))
+        .padding(10)
+        .style(style::title_bar);
+
+    pane_grid::Content::new(content)
+        .title_bar(title_bar)
+        .style(if is_focused {
+            style::pane_focused
+        } else {
+            style::pane_active
+        })
+}
@@ -0,0 +1,64 @@
+use iced::Length;
+use iced::advanced::widget::Id;
+use iced::widget::{
+    Column, button, center, container, pane_grid, scrollable, text,
+};
+use iced_material::Theme;
+
+use super::style;
+use crate::lint::Problem;
+use crate::types::{Element, Message, Project};
+
+/// Lists the accessibility problems of every view and component, showing
+/// an element when its problem is clicked.
+pub fn view<'a>(
+    project: &Project,
+    problems: &[Problem],
+    selected: Option<&Id>,
+    is_focused: bool,
+) -> pane_grid::Content<'a, Message, Theme> {
+    let content: Element<'a, Message> = if problems.is_empty() {
+        center(text("No problems found").size(14)).into()
+    } else {
+        scrollable(
+            Column::with_children(problems.iter().map(|problem| {
+                let location = problem.location.name(project);
+
+                button(text(format!("{location} › {problem}")).size(13))
+                    .on_press(Message::ShowProblem(
+                        problem.location,
+                        problem.id.clone(),
+                    ))
+                    .style(if selected == Some(&problem.id) {
+                        iced_material::button::filled_tonal
+                    } else {
+                        iced_material::button::text
+                    })
+                    .width(Length::Fill)
+                    .into()
+            }))
+            .spacing(5)
+            .padding(10),
+        )
+        .into()
+    };
+
+    let content = container(content).width(Length::Fill).height(Length::Fill);
+
+    let title_bar = pane_grid::TitleBar::new(
+        text(format!("Problems ({})", problems.len())).center(),
+    )
+    .controls(pane_grid::Controls::new(
+        button("Close").on_press(Message::CloseProblems),
+    ))
+    .padding(10)
+    .style(style::title_bar);
+
+    pane_grid::Content::new(content)
+        .title_bar(title_bar)
+        .style(if is_focused {
+            style::pane_focused
+        } else {
+            style::pane_active
+        })
+}
@@ -0,0 +1,55 @@
+use iced::widget::{
+    Column, button, container, pane_grid, row, scrollable, text,
+};
+use iced::{Font, Length};
+use iced_material::Theme;
+
+use super::style;
+use crate::runner::Runner;
+use crate::types::Message;
+
+pub fn view(
+    runner: &Runner,
+    is_focused: bool,
+) -> pane_grid::Content<'_, Message, Theme> {
+    let log = Column::with_children(
+        runner
+            .log()
+            .iter()
+            .map(|line| text(line).font(Font::MONOSPACE).size(13).into()),
+    )
+    .width(Length::Fill)
+    .padding(10);
+
+    let content = container(scrollable(log).anchor_bottom())
+        .width(Length::Fill)
+        .height(Length::Fill);
+
+    let controls = if runner.is_running() {
+        row![
+            button("Restart").on_press(Message::Run),
+            button("Stop").on_press(Message::StopRun),
+        ]
+    } else {
+        row![
+            button("Run").on_press(Message::Run),
+            button("Close").on_press(Message::CloseRunLog),
+        ]
+    }
+    .spacing(10);
+
+    let title_bar = pane_grid::TitleBar::new(
+        text(format!("Run Output ({})", runner.status())).center(),
+    )
+    .controls(pane_grid::Controls::new(controls))
+    .padding(10)
+    .style(style::title_bar);
+
+    pane_grid::Content::new(content)
+        .title_bar(title_bar)
+        .style(if is_focused {
+            style::pane_focused
+        } else {
+            style::pane_active
+        })
+}
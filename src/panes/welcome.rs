@@ -0,0 +1,71 @@
+use std::path::{Path, PathBuf};
+
+use iced::widget::{Column, Row, button, center, column, row, text};
+use iced::{Alignment, Length};
+
+use crate::template::Template;
+use crate::types::{Element, Message};
+
+fn recent_project(path: &Path) -> Element<'_, Message> {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let dir = path
+        .parent()
+        .map(|dir| dir.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    button(column![text(name), text(dir).size(12)].spacing(2))
+        .on_press(Message::OpenRecent(path.to_path_buf()))
+        .style(iced_material::button::text)
+        .width(Length::Fill)
+        .into()
+}
+
+/// Shown in the designer while there's nothing to design yet.
+pub fn view<'a>(
+    recent_projects: &'a [PathBuf],
+    templates: &'a [Template],
+) -> Element<'a, Message> {
+    let recent: Element<'_, Message> = if recent_projects.is_empty() {
+        text("No recent projects").size(14).into()
+    } else {
+        Column::with_children(
+            recent_projects
+                .iter()
+                .map(PathBuf::as_path)
+                .map(recent_project),
+        )
+        .spacing(5)
+        .into()
+    };
+
+    let templates = Row::with_children(templates.iter().map(|template| {
+        button(text(template.name()))
+            .on_press(Message::NewFromTemplate(template.clone()))
+            .style(iced_material::button::filled_tonal)
+            .into()
+    }))
+    .spacing(10)
+    .wrap();
+
+    let content = column![
+        text("iced Builder").size(32),
+        text("Drag an element here to start from scratch, or:").size(14),
+        row![
+            button("New project").on_press(Message::NewFile),
+            button("Open…").on_press(Message::OpenFile),
+        ]
+        .spacing(10),
+        text("Start from a template").size(18),
+        templates,
+        text("Recent projects").size(18),
+        recent,
+    ]
+    .spacing(15)
+    .max_width(500)
+    .align_x(Alignment::Start);
+
+    center(content).padding(20).into()
+}
@@ -0,0 +1,396 @@
+//! The form editing a project's title, theme and app settings.
+use iced::widget::{
+    Column, Space, checkbox, column, container, pick_list, row, scrollable,
+    text, text_input,
+};
+use iced::{Alignment, Color};
+use iced_material::Theme;
+
+use crate::Error;
+use crate::types::project::{
+    CustomPalette, PaletteColor, WindowSize, parse_color,
+};
+use crate::types::{Element, Message, Project};
+use crate::widget;
+
+const MAX_HEIGHT: f32 = 520.0;
+
+#[derive(Debug, Clone)]
+pub enum Change {
+    Title(String),
+    Theme(iced::Theme),
+    Width(String),
+    Height(String),
+    Font(String),
+    TextSize(String),
+    UseCustomPalette(bool),
+    Color(PaletteColor, Color),
+    Hex(PaletteColor, String),
+}
+
+impl From<Change> for Message {
+    fn from(change: Change) -> Self {
+        Self::ProjectSettingsChanged(change)
+    }
+}
+
+/// The settings being edited, as typed by the user.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectSettings {
+    title: String,
+    theme: Option<iced::Theme>,
+    width: String,
+    height: String,
+    font: String,
+    text_size: String,
+    palette: Option<CustomPalette>,
+    /// The palette's colors as typed, in the order of [`PaletteColor::ALL`].
+    hex: Vec<String>,
+    error: Option<String>,
+}
+
+impl ProjectSettings {
+    pub fn new(project: &Project) -> Self {
+        let (width, height) = project
+            .window_size
+            .map(|size| (size.width.to_string(), size.height.to_string()))
+            .unwrap_or_default();
+
+        Self {
+            title: project.title.clone().unwrap_or_default(),
+            // The named theme, even when a custom palette replaces it
+            theme: project.theme.as_deref().and_then(|name| {
+                iced::Theme::ALL
+                    .iter()
+                    .find(|theme| theme.to_string() == name)
+                    .cloned()
+            }),
+            width,
+            height,
+            font: project.default_font.clone().unwrap_or_default(),
+            text_size: project
+                .text_size
+                .map(|size| size.to_string())
+                .unwrap_or_default(),
+            palette: project.palette,
+            hex: hex_strings(project.palette.as_ref()),
+            error: None,
+        }
+    }
+
+    pub fn update(&mut self, change: Change) {
+        match change {
+            Change::Title(title) => self.title = title,
+            Change::Theme(theme) => self.theme = Some(theme),
+            Change::Width(width) => self.width = width,
+            Change::Height(height) => self.height = height,
+            Change::Font(font) => self.font = font,
+            Change::TextSize(text_size) => self.text_size = text_size,
+            Change::UseCustomPalette(use_palette) => {
+                // Start from the colors of the selected theme
+                self.palette = use_palette.then(|| {
+                    self.theme.clone().unwrap_or_default().palette().into()
+                });
+                self.hex = hex_strings(self.palette.as_ref());
+            }
+            Change::Color(color, value) => {
+                if let Some(palette) = &mut self.palette {
+                    palette.set(color, value);
+                    self.hex = hex_strings(Some(palette));
+                }
+            }
+            Change::Hex(color, hex) => {
+                if let Some(palette) = &mut self.palette
+                    && let Some(value) = parse_color(&hex)
+                {
+                    palette.set(color, value);
+                }
+                if let Some(typed) = self.hex.get_mut(color as usize) {
+                    *typed = hex;
+                }
+            }
+        }
+    }
+
+    /// Writes the settings to `project`, or keeps the form open with an
+    /// error if some of them are invalid.
+    pub fn apply(&mut self, project: &mut Project) -> Result<(), Error> {
+        let result = self.parse();
+
+        match result {
+            Ok((window_size, text_size)) => {
+                project.title = non_empty(&self.title);
+                project.theme =
+                    self.theme.as_ref().map(ToString::to_string).or_else(
+                        || {
+                            // Keep a theme we don't know about
+                            project.unknown_theme().map(str::to_owned)
+                        },
+                    );
+                project.window_size = window_size;
+                project.default_font = non_empty(&self.font);
+                project.text_size = text_size;
+                project.palette = self.palette;
+                self.error = None;
+
+                Ok(())
+            }
+            Err(error) => {
+                self.error = Some(error.to_string());
+
+                Err(error)
+            }
+        }
+    }
+
+    fn parse(&self) -> Result<(Option<WindowSize>, Option<f32>), Error> {
+        let window_size = match (
+            positive(&self.width, "window width")?,
+            positive(&self.height, "window height")?,
+        ) {
+            (Some(width), Some(height)) => Some(WindowSize { width, height }),
+            (None, None) => None,
+            _ => {
+                return Err(
+                    "The window size needs both a width and a height".into()
+                );
+            }
+        };
+
+        if self.palette.is_some() {
+            for (color, hex) in PaletteColor::ALL.iter().zip(&self.hex) {
+                if parse_color(hex).is_none() {
+                    return Err(format!(
+                        "The {} color has to look like #rrggbb",
+                        color.to_string().to_lowercase()
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Ok((window_size, positive(&self.text_size, "text size")?))
+    }
+
+    fn palette_editor(&self) -> Element<'_, Message> {
+        let Some(palette) = &self.palette else {
+            return Space::new(0, 0).into();
+        };
+
+        Column::with_children(PaletteColor::ALL.iter().zip(&self.hex).map(
+            |(&color, hex)| {
+                let value = palette.get(color);
+
+                column![
+                    row![
+                        widget::color::swatch(value),
+                        text(color.to_string()).width(90),
+                        text_input("#rrggbb", hex)
+                            .on_input(move |hex| Change::Hex(color, hex).into())
+                            .width(110),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
+                    widget::color::sliders(
+                        value,
+                        move |value| Change::Color(color, value).into(),
+                        None,
+                    ),
+                ]
+                .spacing(5)
+                .into()
+            },
+        ))
+        .spacing(15)
+        .into()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let field = |label: &'static str,
+                     content: Element<'static, Message>| {
+            column![text(label).size(12), content].spacing(4)
+        };
+
+        let mut form = column![
+            field(
+                "Title",
+                text_input("New app", &self.title)
+                    .on_input(|title| Change::Title(title).into())
+                    .into(),
+            ),
+            field(
+                if self.palette.is_some() {
+                    "Theme the palette started from"
+                } else {
+                    "Theme"
+                },
+                pick_list(iced::Theme::ALL, self.theme.clone(), |theme| {
+                    Change::Theme(theme).into()
+                })
+                .placeholder("Default")
+                .into(),
+            ),
+            checkbox("Custom palette", self.palette.is_some()).on_toggle(
+                |use_palette| { Change::UseCustomPalette(use_palette).into() }
+            ),
+            self.palette_editor(),
+            row![
+                field(
+                    "Window width",
+                    text_input("Default", &self.width)
+                        .on_input(|width| Change::Width(width).into())
+                        .into(),
+                ),
+                field(
+                    "Window height",
+                    text_input("Default", &self.height)
+                        .on_input(|height| Change::Height(height).into())
+                        .into(),
+                ),
+            ]
+            .spacing(10),
+            field(
+                "Default font",
+                text_input("Default", &self.font)
+                    .on_input(|font| Change::Font(font).into())
+                    .into(),
+            ),
+            field(
+                "Text size",
+                text_input("Default", &self.text_size)
+                    .on_input(|text_size| Change::TextSize(text_size).into())
+                    .into(),
+            ),
+        ]
+        .spacing(15)
+        .width(480);
+
+        if let Some(error) = &self.error {
+            form = form.push(text(error).style(|theme: &Theme| text::Style {
+                color: Some(theme.colors().error.color),
+            }));
+        }
+
+        // The palette editor doesn't fit on small windows
+        container(scrollable(form.padding([0, 15])))
+            .max_height(MAX_HEIGHT)
+            .into()
+    }
+}
+
+fn hex_strings(palette: Option<&CustomPalette>) -> Vec<String> {
+    use crate::values::Value;
+
+    palette
+        .map(|palette| {
+            PaletteColor::ALL
+                .iter()
+                .map(|color| Value::to_string(&palette.get(*color)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+
+    (!value.is_empty()).then(|| value.to_owned())
+}
+
+/// Parses an optional, positive number typed into the `name` field.
+fn positive(value: &str, name: &str) -> Result<Option<f32>, Error> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    match value.parse::<f32>() {
+        Ok(number) if number > 0.0 && number.is_finite() => Ok(Some(number)),
+        _ => Err(format!("The {name} has to be a positive number").into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_positive_numbers() {
+        assert_eq!(positive(" 800 ", "width").ok(), Some(Some(800.0)));
+        assert_eq!(positive("", "width").ok(), Some(None));
+        assert!(positive("-1", "width").is_err());
+        assert!(positive("wide", "width").is_err())
+    }
+
+    #[test]
+    fn window_size_needs_both_dimensions() {
+        let settings = ProjectSettings {
+            width: String::from("800"),
+            ..ProjectSettings::default()
+        };
+
+        assert!(settings.parse().is_err())
+    }
+
+    #[test]
+    fn palette_starts_from_theme() {
+        let mut settings = ProjectSettings {
+            theme: Some(iced::Theme::Nord),
+            ..ProjectSettings::default()
+        };
+
+        settings.update(Change::UseCustomPalette(true));
+
+        assert_eq!(
+            settings.palette.map(|palette| palette.primary),
+            Some(iced::Theme::Nord.palette().primary)
+        );
+        assert_eq!(settings.hex.len(), PaletteColor::ALL.len())
+    }
+
+    #[test]
+    fn invalid_hex_is_an_error() {
+        let mut settings = ProjectSettings::default();
+        settings.update(Change::UseCustomPalette(true));
+        settings.update(Change::Hex(PaletteColor::Text, String::from("#12")));
+
+        assert!(settings.parse().is_err())
+    }
+
+    #[test]
+    fn keeps_the_named_theme_with_a_custom_palette() {
+        let mut project = Project::new();
+        project.theme = Some(String::from("Nord"));
+        project.palette = Some(iced::Theme::Dracula.palette().into());
+
+        let mut settings = ProjectSettings::new(&project);
+        assert_eq!(settings.theme, Some(iced::Theme::Nord));
+
+        assert!(settings.apply(&mut project).is_ok());
+        assert_eq!(project.theme.as_deref(), Some("Nord"))
+    }
+
+    #[test]
+    fn can_apply_settings() {
+        let mut project = Project::new();
+        let mut settings = ProjectSettings {
+            title: String::from("Counter"),
+            theme: Some(iced::Theme::Nord),
+            width: String::from("800"),
+            height: String::from("600"),
+            ..ProjectSettings::default()
+        };
+
+        assert!(settings.apply(&mut project).is_ok());
+        assert_eq!(project.title.as_deref(), Some("Counter"));
+        assert_eq!(project.theme.as_deref(), Some("Nord"));
+        assert_eq!(
+            project.window_size,
+            Some(WindowSize {
+                width: 800.0,
+                height: 600.0
+            })
+        );
+        assert_eq!(project.text_size, None)
+    }
+}
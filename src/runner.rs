@@ -0,0 +1,224 @@
+use std::path::{Path, PathBuf};
+use std::process::{ExitStatus, Stdio};
+
+use iced::futures::channel::mpsc;
+use iced::futures::{SinkExt, Stream};
+use iced::task;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::SplitStream;
+
+use crate::Error;
+use crate::export::Scaffold;
+
+/// The maximum amount of lines kept in the run log.
+const MAX_LOG_LINES: usize = 5000;
+
+/// Something that happened in a run.
+#[derive(Debug, Clone)]
+pub struct Event {
+    /// The run it happened in, so a stopped run can't affect the next one.
+    run: u64,
+    kind: Kind,
+}
+
+#[derive(Debug, Clone)]
+enum Kind {
+    Output(String),
+    Finished(Result<ExitStatus, Error>),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Status {
+    #[default]
+    Idle,
+    Running,
+    Stopped,
+    Finished(Option<i32>),
+    Failed,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Idle => f.write_str("Idle"),
+            Self::Running => f.write_str("Running"),
+            Self::Stopped => f.write_str("Stopped"),
+            Self::Finished(Some(code)) => write!(f, "Exited with code {code}"),
+            Self::Finished(None) => f.write_str("Exited"),
+            Self::Failed => f.write_str("Failed"),
+        }
+    }
+}
+
+/// Compiles and runs the generated app as a child process.
+#[derive(Debug, Default)]
+pub struct Runner {
+    handle: Option<task::Handle>,
+    /// The number of the latest run.
+    run: u64,
+    status: Status,
+    log: Vec<String>,
+}
+
+impl Runner {
+    pub fn dir() -> PathBuf {
+        std::env::temp_dir().join("iced-builder-preview")
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status == Status::Running
+    }
+
+    pub fn log(&self) -> &[String] {
+        &self.log
+    }
+
+    /// Starts a new run, stopping the previous one if there is one.
+    pub fn start(&mut self, scaffold: Scaffold) -> iced::Task<Event> {
+        self.stop();
+        self.log.clear();
+        self.status = Status::Running;
+        self.run += 1;
+
+        let run = self.run;
+        let (task, handle) = iced::Task::stream(self::run(scaffold))
+            .map(move |kind| Event { run, kind })
+            .abortable();
+        self.handle = Some(handle.abort_on_drop());
+
+        task
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+
+            if self.is_running() {
+                self.status = Status::Stopped;
+                self.log.push(String::from("Stopped."));
+            }
+        }
+    }
+
+    pub fn update(&mut self, event: Event) {
+        // Whatever a stopped run sent before it was aborted
+        if event.run != self.run {
+            return;
+        }
+
+        match event.kind {
+            Kind::Output(line) => {
+                self.log.push(line);
+
+                if self.log.len() > MAX_LOG_LINES {
+                    let _ = self.log.drain(..self.log.len() - MAX_LOG_LINES);
+                }
+            }
+            Kind::Finished(result) => {
+                self.handle = None;
+                self.status = match result {
+                    Ok(status) => Status::Finished(status.code()),
+                    Err(error) => {
+                        self.log.push(error.to_string());
+                        Status::Failed
+                    }
+                };
+            }
+        }
+    }
+}
+
+fn run(scaffold: Scaffold) -> impl Stream<Item = Kind> {
+    iced::stream::channel(100, |mut output| async move {
+        let result = spawn(scaffold, &mut output).await;
+
+        let _ = output.send(Kind::Finished(result)).await;
+    })
+}
+
+async fn spawn(
+    scaffold: Scaffold,
+    output: &mut mpsc::Sender<Kind>,
+) -> Result<ExitStatus, Error> {
+    let dir = Runner::dir();
+    let target_dir = dir.join("target");
+    let binary = target_dir.join("debug").join(format!(
+        "{}{}",
+        scaffold.name,
+        std::env::consts::EXE_SUFFIX
+    ));
+
+    scaffold.write(&dir).await?;
+
+    let _ = output
+        .send(Kind::Output(format!(
+            "Building in {}",
+            dir.to_string_lossy()
+        )))
+        .await;
+
+    // The app is built and launched as separate steps, so stopping a run
+    // kills the app itself instead of only the `cargo` process wrapping it.
+    let mut build = Command::new("cargo");
+    let _ = build
+        .args(["build", "--offline", "--color", "never"])
+        .env("CARGO_TARGET_DIR", &target_dir);
+
+    let status = stream_output(build, &dir, output).await?;
+    if !status.success() {
+        return Ok(status);
+    }
+
+    stream_output(Command::new(binary), &dir, output).await
+}
+
+async fn stream_output(
+    mut command: Command,
+    dir: &Path,
+    output: &mut mpsc::Sender<Kind>,
+) -> Result<ExitStatus, Error> {
+    let mut child = command
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()?;
+
+    let stdout = child.stdout.take().ok_or("failed to capture stdout")?;
+    let stderr = child.stderr.take().ok_or("failed to capture stderr")?;
+
+    // Read as bytes, so that output that isn't valid UTF-8 doesn't end the
+    // run
+    let mut lines = SplitStream::new(BufReader::new(stdout).split(b'\n'))
+        .merge(SplitStream::new(BufReader::new(stderr).split(b'\n')));
+
+    while let Some(line) = lines.next().await {
+        let _ = output.send(Kind::Output(decode(&line?))).await;
+    }
+
+    Ok(child.wait().await?)
+}
+
+/// Decodes a line of output, replacing what isn't valid UTF-8.
+fn decode(line: &[u8]) -> String {
+    String::from_utf8_lossy(line.strip_suffix(b"\r").unwrap_or(line))
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_invalid_utf8_lossily() {
+        assert_eq!(decode(b"caf\xe9\r"), "caf\u{FFFD}");
+        assert_eq!(decode("café".as_bytes()), "café")
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::ReadDirStream;
+
+use crate::Error;
+use crate::config::Config;
+use crate::types::Project;
+
+/// Templates shipped with iced Builder, as project JSON.
+const BUILT_IN: &[(&str, &str)] = &[
+    ("Login form", include_str!("../templates/login_form.json")),
+    (
+        "Settings page",
+        include_str!("../templates/settings_page.json"),
+    ),
+    (
+        "Sidebar and content",
+        include_str!("../templates/sidebar_content.json"),
+    ),
+    (
+        "Dashboard cards",
+        include_str!("../templates/dashboard_cards.json"),
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    BuiltIn(&'static str),
+    /// A project file in the templates folder of the config directory.
+    File(PathBuf),
+}
+
+/// A project new projects can start from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    name: String,
+    source: Source,
+}
+
+impl Template {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Creates a new, unsaved project from the template.
+    pub async fn instantiate(self) -> Result<Project, Error> {
+        match self.source {
+            Source::BuiltIn(json) => Project::from_json(json),
+            Source::File(path) => {
+                let (_, _, mut project) =
+                    Project::from_path(path.clone()).await?;
+
+                // The project isn't saved anywhere yet, so asset paths
+                // relative to the template would break
+                project.rebase_assets(Some(&path), None);
+
+                Ok(project)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Lists the built-in templates followed by the ones the user added.
+pub async fn list() -> Vec<Template> {
+    use tokio::fs;
+
+    let mut templates: Vec<Template> = BUILT_IN
+        .iter()
+        .map(|(name, json)| Template {
+            name: (*name).to_owned(),
+            source: Source::BuiltIn(json),
+        })
+        .collect();
+
+    let Ok(dir) = fs::read_dir(Config::templates_dir()).await else {
+        return templates;
+    };
+
+    let mut user_templates = Vec::new();
+    let mut stream = ReadDirStream::new(dir);
+    while let Some(entry) = stream.next().await {
+        let Ok(entry) = entry else {
+            continue;
+        };
+
+        let path = entry.path();
+        if !Project::is_project_file(&path) {
+            continue;
+        }
+
+        if let Some(name) = path.file_stem() {
+            user_templates.push(Template {
+                name: name.to_string_lossy().into_owned(),
+                source: Source::File(path),
+            });
+        }
+    }
+
+    user_templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates.extend(user_templates);
+
+    templates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_templates_are_valid() {
+        for (name, json) in BUILT_IN {
+            let project = Project::from_json(json);
+
+            assert!(project.is_ok(), "{name}: {project:?}");
+            assert!(project.unwrap().element_tree().is_some(), "{name}")
+        }
+    }
+}
@@ -1,6 +1,12 @@
+pub mod asset;
+pub mod component;
 pub mod element_name;
+pub mod font;
 pub mod project;
 pub mod rendered_element;
+pub mod span;
+pub mod style;
+pub mod viewport;
 
 use std::path::PathBuf;
 
@@ -15,6 +21,11 @@ pub use rendered_element::*;
 
 use crate::Error;
 use crate::config::Config;
+use crate::document;
+use crate::lint::Location;
+use crate::project_settings;
+use crate::runner;
+use crate::template::Template;
 
 pub type Element<'a, Message> = iced::Element<'a, Message, Theme>;
 
@@ -27,6 +38,16 @@ pub enum Message {
     SwitchTheme(Event<Theme>),
     SystemThemeChanged(Theme),
     CopyCode,
+    ExportCode,
+    ExportCargoProject,
+    Exported(Result<PathBuf, Error>),
+    Run,
+    StopRun,
+    RunEvent(runner::Event),
+    CloseRunLog,
+    ShowProblems,
+    ShowProblem(Location, Id),
+    CloseProblems,
     SwitchPane(DesignerPane),
     EditorAction(text_editor::Action),
     RefreshEditorContent,
@@ -34,6 +55,38 @@ pub enum Message {
     HandleNew(ElementName, Vec<(Id, iced::Rectangle)>),
     MoveElement(RenderedElement, iced::Point, iced::Rectangle),
     HandleMove(RenderedElement, Vec<(Id, iced::Rectangle)>),
+    SelectView(usize),
+    AddView,
+    RenameView(String),
+    RemoveView,
+    SelectElement(Id),
+    ComponentNameChanged(String),
+    PromoteToComponent,
+    EditComponent(usize),
+    StopEditingComponent,
+    SetOverride(String, String),
+    SetStyle(style::ElementStyle),
+    PreviewButtonStatus(style::ButtonStatus),
+    CompareThemes(Vec<iced::Theme>),
+    SetViewport(viewport::Viewport),
+    SetPreview(bool),
+    SetLayoutDebug(bool),
+    /// The size of the element hovered in one of the previews, by index.
+    LayoutHovered(usize, Option<iced::Size>),
+    SetFont(font::ElementFont),
+    /// Makes the family names typed into the selected element usable.
+    CommitFonts,
+    SetSpans(Vec<span::Span>),
+    /// A value changing while a slider is dragged.
+    Sliding(Box<Message>),
+    SliderReleased,
+    SetDescription(String),
+    FontLoaded(Result<(), Error>),
+    AddAssets,
+    AssetsPicked(Result<Vec<PathBuf>, Error>),
+    RemoveAsset(usize),
+    SetAssetMode(asset::AssetMode),
+    DropAsset(PathBuf, iced::Point, iced::Rectangle),
     PaneResized(pane_grid::ResizeEvent),
     PaneClicked(pane_grid::Pane),
     PaneDragged(pane_grid::DragEvent),
@@ -42,20 +95,44 @@ pub enum Message {
     DialogNo,
     DialogCancel,
     NewFile,
+    OpenProjectSettings,
+    ProjectSettingsChanged(project_settings::Change),
+    TemplatesLoaded(Vec<Template>),
+    NewFromTemplate(Template),
+    TemplateInstantiated(Result<Project, Error>),
     OpenFile,
-    FileOpened(Result<(PathBuf, Project), Error>),
+    OpenRecent(PathBuf),
+    FileOpened(Result<(PathBuf, u64, Project), Error>),
     SaveFile,
     SaveFileAs,
-    FileSaved(Result<PathBuf, Error>),
+    FileSaved(document::Key, Result<(PathBuf, u64), Error>),
+    FileReloaded(document::Key, Result<(PathBuf, u64, Project), Error>),
+    SelectDocument(usize),
+    CloseDocument(usize),
+    Undo,
+    Redo,
+    CheckProjectFile,
+    /// The hash the document knew when the check started, and the one of
+    /// the file on disk.
+    ProjectFileChecked(document::Key, Option<u64>, Option<u64>),
+    ShowExternalDiff,
+    ExternalDiff(Result<String, Error>),
+    Autosave,
+    Autosaved(Result<(), Error>),
+    Recovered(Result<Vec<(Option<PathBuf>, Project)>, Error>),
     CloseApp,
     EscapePressed,
     WindowEvent(window::Event),
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Panes {
     Designer,
     ElementList,
+    Inspector,
+    AssetLibrary,
+    RunLog,
+    Problems,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -72,7 +149,7 @@ impl From<DesignerPane> for Message {
 
 #[derive(Debug, Clone, Copy)]
 pub enum ConfigChangeType {
-    LastProject,
+    RecentProjects,
     SelectedTheme,
 }
 
@@ -0,0 +1,196 @@
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+pub const IMAGE_EXTENSIONS: &[&str] =
+    &["png", "jpg", "jpeg", "gif", "bmp", "webp", "ico", "tiff"];
+pub const SVG_EXTENSIONS: &[&str] = &["svg"];
+pub const FONT_EXTENSIONS: &[&str] = &["ttf", "otf"];
+
+/// How generated code refers to the images and SVGs of a project.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum AssetMode {
+    /// Load files from disk at runtime, relative to the working directory.
+    #[default]
+    Runtime,
+    /// Embed the files into the binary with `include_bytes!`.
+    Embedded,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetKind {
+    Image,
+    Svg,
+    /// A font bundled with the app.
+    Font,
+}
+
+impl AssetKind {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+
+        if SVG_EXTENSIONS.contains(&extension.as_str()) {
+            Some(Self::Svg)
+        } else if FONT_EXTENSIONS.contains(&extension.as_str()) {
+            Some(Self::Font)
+        } else if IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+            Some(Self::Image)
+        } else {
+            None
+        }
+    }
+}
+
+/// Resolves an asset path stored in a project against the directory of the
+/// project file.
+pub fn resolve(path: &Path, project_path: Option<&Path>) -> PathBuf {
+    match project_path.and_then(Path::parent) {
+        Some(dir) if path.is_relative() => dir.join(path),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Turns `path` into a path relative to the directory of the project file,
+/// if the project has been saved before.
+pub fn relativize(path: &Path, project_path: Option<&Path>) -> PathBuf {
+    let Some(dir) = project_path.and_then(Path::parent) else {
+        return path.to_path_buf();
+    };
+
+    if path.is_relative() {
+        return path.to_path_buf();
+    }
+
+    let path_components: Vec<Component<'_>> = path.components().collect();
+    let dir_components: Vec<Component<'_>> = dir.components().collect();
+
+    let common = path_components
+        .iter()
+        .zip(&dir_components)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    // Paths on different drives or roots can't be made relative
+    if common == 0 {
+        return path.to_path_buf();
+    }
+
+    let mut relative = PathBuf::new();
+    for _ in common..dir_components.len() {
+        relative.push("..");
+    }
+    for component in &path_components[common..] {
+        relative.push(component);
+    }
+
+    relative
+}
+
+/// Returns the paths in `paths` that don't point to an existing file.
+pub fn missing<'a>(
+    paths: impl IntoIterator<Item = &'a Path>,
+    project_path: Option<&Path>,
+) -> Vec<PathBuf> {
+    let mut missing: Vec<PathBuf> = Vec::new();
+
+    for path in paths {
+        if !resolve(path, project_path).is_file()
+            && !missing.iter().any(|p| p == path)
+        {
+            missing.push(path.to_path_buf());
+        }
+    }
+
+    missing
+}
+
+pub async fn pick() -> Result<Vec<PathBuf>, Error> {
+    let extensions: Vec<&str> = IMAGE_EXTENSIONS
+        .iter()
+        .chain(SVG_EXTENSIONS)
+        .chain(FONT_EXTENSIONS)
+        .copied()
+        .collect();
+
+    let picked_files = rfd::AsyncFileDialog::new()
+        .set_title("Add assets...")
+        .add_filter("Images, SVGs and fonts", &extensions)
+        .pick_files()
+        .await
+        .ok_or(Error::DialogClosed)?;
+
+    Ok(picked_files
+        .iter()
+        .map(|file| file.path().to_owned())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_detect_kind() {
+        assert_eq!(
+            AssetKind::from_path(Path::new("logo.SVG")),
+            Some(AssetKind::Svg)
+        );
+
+        assert_eq!(
+            AssetKind::from_path(Path::new("photo.jpeg")),
+            Some(AssetKind::Image)
+        );
+
+        assert_eq!(
+            AssetKind::from_path(Path::new("Inter-Bold.ttf")),
+            Some(AssetKind::Font)
+        );
+
+        assert_eq!(AssetKind::from_path(Path::new("notes.txt")), None)
+    }
+
+    #[test]
+    fn can_relativize_sibling() {
+        assert_eq!(
+            relativize(
+                Path::new("/home/user/app/assets/logo.png"),
+                Some(Path::new("/home/user/app/project.json"))
+            ),
+            PathBuf::from("assets/logo.png")
+        )
+    }
+
+    #[test]
+    fn can_relativize_outside_project_dir() {
+        assert_eq!(
+            relativize(
+                Path::new("/home/user/images/logo.png"),
+                Some(Path::new("/home/user/app/project.json"))
+            ),
+            PathBuf::from("../images/logo.png")
+        )
+    }
+
+    #[test]
+    fn doesnt_relativize_without_project_path() {
+        assert_eq!(
+            relativize(Path::new("/home/user/logo.png"), None),
+            PathBuf::from("/home/user/logo.png")
+        )
+    }
+
+    #[test]
+    fn can_resolve_relative_path() {
+        assert_eq!(
+            resolve(
+                Path::new("assets/logo.png"),
+                Some(Path::new("/home/user/app/project.json"))
+            ),
+            PathBuf::from("/home/user/app/assets/logo.png")
+        )
+    }
+}
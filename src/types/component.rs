@@ -0,0 +1,261 @@
+//! Code generation for components, subtrees shared by several instances.
+//!
+//! Instances can override the text and options of their component's
+//! elements. Overrides are stored in the options of the instance, keyed by
+//! [`override_key`].
+use std::collections::BTreeMap;
+
+use iced::widget::text::LineHeight;
+use iced::{Alignment, ContentFit, Length, Padding, Pixels, Rotation};
+
+use crate::types::asset::AssetMode;
+use crate::values::Value;
+
+/// Builds the key of an override, from the path of child indices leading to
+/// the overridden element and the name of the option, or `text`.
+pub fn override_key(path: &[usize], option: &str) -> String {
+    let path: Vec<String> = path.iter().map(ToString::to_string).collect();
+
+    format!("{}:{option}", path.join("."))
+}
+
+/// Splits an override key into the path of the element and the option.
+pub fn parse_override_key(key: &str) -> Option<(Vec<usize>, &str)> {
+    let (path, option) = key.rsplit_once(':')?;
+
+    let path = if path.is_empty() {
+        Vec::new()
+    } else {
+        path.split('.')
+            .map(str::parse)
+            .collect::<Result<Vec<usize>, _>>()
+            .ok()?
+    };
+
+    Some((path, option))
+}
+
+/// Something an instance can override in its component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverrideSlot {
+    pub key: String,
+    pub label: String,
+    /// The value set in the component.
+    pub value: String,
+}
+
+/// What code generation needs to know besides the element tree.
+#[derive(Debug, Clone, Copy)]
+pub struct Codegen<'a> {
+    pub asset_mode: AssetMode,
+    pub components: &'a [Signature],
+    /// The parameters of the component function being generated.
+    pub params: &'a [Param],
+}
+
+impl<'a> Codegen<'a> {
+    pub fn new(asset_mode: AssetMode, components: &'a [Signature]) -> Self {
+        Self {
+            asset_mode,
+            components,
+            params: &[],
+        }
+    }
+
+    /// The name of the parameter replacing the value of `key`, if any.
+    pub fn param(&self, key: &str) -> Option<&'a str> {
+        self.params
+            .iter()
+            .find(|param| param.key == key)
+            .map(|param| param.name.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub key: String,
+    pub name: String,
+    pub ty: String,
+    /// The value passed by instances that don't override it.
+    pub default: String,
+}
+
+impl Param {
+    /// Creates the parameter for the override slot `slot`.
+    pub fn new(slot: &OverrideSlot) -> Self {
+        let (path, option) = parse_override_key(&slot.key).unwrap_or_default();
+
+        let mut name = option.to_owned();
+        for index in path {
+            name = format!("{name}_{index}");
+        }
+
+        let (ty, default) = if option == "text" {
+            (String::from("&'a str"), format!("{:?}", slot.value))
+        } else {
+            (
+                format!("impl Into<{}>", option_type(option)),
+                slot.value.clone(),
+            )
+        };
+
+        Self {
+            key: slot.key.clone(),
+            name,
+            ty,
+            default,
+        }
+    }
+
+    fn argument(&self, overrides: &BTreeMap<String, Option<String>>) -> String {
+        match overrides.get(&self.key) {
+            Some(Some(value)) if self.key.ends_with(":text") => {
+                format!("{value:?}")
+            }
+            Some(Some(value)) => value.clone(),
+            _ => self.default.clone(),
+        }
+    }
+}
+
+/// The function generated for a component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub name: String,
+    pub function: String,
+    pub params: Vec<Param>,
+}
+
+impl Signature {
+    /// Calls the component function for an instance with `overrides`.
+    pub fn call(&self, overrides: &BTreeMap<String, Option<String>>) -> String {
+        let arguments: Vec<String> = self
+            .params
+            .iter()
+            .map(|param| param.argument(overrides))
+            .collect();
+
+        format!("{}({})", self.function, arguments.join(", "))
+    }
+
+    /// Defines the component function, drawing `body`.
+    pub fn definition(&self, body: &str) -> String {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|param| format!("{}: {}", param.name, param.ty))
+            .collect();
+
+        format!(
+            "fn {}<'a>({}) -> Element<'a, Message> {{\n    {body}.into()\n}}",
+            self.function,
+            params.join(", ")
+        )
+    }
+}
+
+/// The type the widget method for `option` accepts.
+fn option_type(option: &str) -> &'static str {
+    match option {
+        "size" | "spacing" | "max_width" | "max_height" => "iced::Pixels",
+        "padding" => "iced::Padding",
+        "line_height" => "iced::widget::text::LineHeight",
+        "align_x" | "align_y" => "iced::Alignment",
+        "content_fit" => "iced::ContentFit",
+        "rotation" => "iced::Rotation",
+        "opacity" | "scale" => "f32",
+        "clip" => "bool",
+        _ => "iced::Length",
+    }
+}
+
+/// Whether an instance can override `key` with `value`, that is whether it
+/// parses as the type of the overridden option. An empty `value` resets
+/// the override, so it's always valid.
+pub fn is_valid_override(key: &str, value: &str) -> bool {
+    let Some((_, option)) = parse_override_key(key) else {
+        return false;
+    };
+
+    if value.is_empty() {
+        return true;
+    }
+
+    match option {
+        "text" => true,
+        "size" | "spacing" | "max_width" | "max_height" => {
+            Pixels::from_str(value).is_ok()
+        }
+        "padding" => Padding::from_str(value).is_ok(),
+        "line_height" => LineHeight::from_str(value).is_ok(),
+        "align_x" | "align_y" => Alignment::from_str(value).is_ok(),
+        "content_fit" => ContentFit::from_str(value).is_ok(),
+        "rotation" => Rotation::from_str(value).is_ok(),
+        "opacity" | "scale" => value.parse::<f32>().is_ok(),
+        "clip" => value.parse::<bool>().is_ok(),
+        _ => Length::from_str(value).is_ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_round_trip_override_key() {
+        let key = override_key(&[0, 2], "size");
+
+        assert_eq!(key, "0.2:size");
+
+        assert_eq!(parse_override_key(&key), Some((vec![0, 2], "size")))
+    }
+
+    #[test]
+    fn rejects_partly_typed_overrides() {
+        assert!(is_valid_override("0:size", "16"));
+        assert!(!is_valid_override("0:size", "F"));
+        assert!(!is_valid_override(":width", "fx"));
+        assert!(is_valid_override(":width", ""));
+        assert!(is_valid_override("1:text", "F"))
+    }
+
+    #[test]
+    fn can_parse_root_override_key() {
+        assert_eq!(parse_override_key(":text"), Some((vec![], "text")))
+    }
+
+    #[test]
+    fn can_call_with_overrides() {
+        let signature = Signature {
+            name: String::from("Card"),
+            function: String::from("card_component"),
+            params: vec![
+                Param::new(&OverrideSlot {
+                    key: String::from("0:text"),
+                    label: String::new(),
+                    value: String::from("Users"),
+                }),
+                Param::new(&OverrideSlot {
+                    key: String::from(":padding"),
+                    label: String::new(),
+                    value: String::from("20"),
+                }),
+            ],
+        };
+
+        let overrides = BTreeMap::from([(
+            String::from("0:text"),
+            Some(String::from("Orders")),
+        )]);
+
+        assert_eq!(
+            signature.call(&overrides),
+            "card_component(\"Orders\", 20)"
+        );
+
+        assert_eq!(
+            signature.definition("column![text(text_0)]"),
+            "fn card_component<'a>(text_0: &'a str, padding: impl Into<iced::Padding>) -> Element<'a, Message> {\n    column![text(text_0)].into()\n}"
+        )
+    }
+}
@@ -1,24 +1,32 @@
 use serde::{Deserialize, Serialize};
 
+use super::font::{ElementFont, Weight};
 use super::rendered_element::{
-    Action, RenderedElement, button, column, container, image, row, svg, text,
+    Action, RenderedElement, button, column, component, container, image,
+    rich_text, row, svg, text,
 };
+use super::span::Span;
 use crate::Error;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ElementName {
     Text(String),
+    /// A text made of differently styled spans.
+    RichText(Vec<Span>),
     Button(String),
     Svg(String),
     Image(String),
     Container,
     Row,
     Column,
+    /// An instance of the project's component with this name.
+    Component(String),
 }
 
 impl ElementName {
-    pub const ALL: &'static [Self; 7] = &[
+    pub const ALL: &'static [Self; 8] = &[
         Self::Text(String::new()),
+        Self::RichText(Vec::new()),
         Self::Button(String::new()),
         Self::Svg(String::new()),
         Self::Image(String::new()),
@@ -27,6 +35,71 @@ impl ElementName {
         Self::Column,
     ];
 
+    /// The options an element of this kind can be configured with.
+    pub fn option_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Text(_) => &[
+                "size",
+                "line_height",
+                "width",
+                "height",
+                "align_x",
+                "align_y",
+            ],
+            Self::RichText(_) => &[
+                "size",
+                "line_height",
+                "width",
+                "height",
+                "align_x",
+                "align_y",
+            ],
+            Self::Button(_) => &["width", "height", "padding", "clip"],
+            Self::Svg(_) => {
+                &["width", "height", "content_fit", "rotation", "opacity"]
+            }
+            Self::Image(_) => &[
+                "width",
+                "height",
+                "content_fit",
+                "rotation",
+                "opacity",
+                "scale",
+            ],
+            Self::Container => &[
+                "padding",
+                "width",
+                "height",
+                "max_width",
+                "max_height",
+                "center_x",
+                "center_y",
+                "center",
+                "align_left",
+                "align_right",
+                "align_top",
+                "align_bottom",
+                "align_x",
+                "align_y",
+                "clip",
+            ],
+            Self::Row => {
+                &["spacing", "padding", "width", "height", "align_y", "clip"]
+            }
+            Self::Column => &[
+                "spacing",
+                "padding",
+                "width",
+                "height",
+                "max_width",
+                "align_x",
+                "clip",
+            ],
+            // Instances store overrides of their component instead
+            Self::Component(_) => &[],
+        }
+    }
+
     pub fn handle_action(
         &self,
         element_tree: Option<&mut RenderedElement>,
@@ -34,12 +107,23 @@ impl ElementName {
     ) -> Result<Option<RenderedElement>, Error> {
         let element = match self {
             Self::Text(_) => text(""),
+            Self::RichText(_) => rich_text(vec![
+                Span::new("New "),
+                Span {
+                    font: ElementFont {
+                        weight: Weight::Bold,
+                        ..ElementFont::default()
+                    },
+                    ..Span::new("Rich Text")
+                },
+            ]),
             Self::Button(_) => button(""),
-            Self::Svg(_) => svg(""),
-            Self::Image(_) => image(""),
+            Self::Svg(path) => svg(path),
+            Self::Image(path) => image(path),
             Self::Container => container(None),
             Self::Row => row(vec![]),
             Self::Column => column(vec![]),
+            Self::Component(name) => component(name),
         };
         match action {
             Action::Stop | Action::Drop => Ok(None),
@@ -73,12 +157,14 @@ impl std::fmt::Display for ElementName {
             "{}",
             match self {
                 Self::Text(_) => "Text",
+                Self::RichText(_) => "Rich Text",
                 Self::Button(_) => "Button",
                 Self::Svg(_) => "SVG",
                 Self::Image(_) => "Image",
                 Self::Container => "Container",
                 Self::Row => "Row",
                 Self::Column => "Column",
+                Self::Component(_) => "Component",
             }
         )
     }
@@ -0,0 +1,461 @@
+//! The fonts of texts and button labels.
+use std::collections::BTreeSet;
+use std::sync::{LazyLock, Mutex};
+
+use iced::font;
+use iced::widget::text::Shaping;
+use serde::{Deserialize, Serialize};
+
+/// A font family, stored as its CSS-like generic name or the name of the
+/// family.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Family {
+    SansSerif,
+    Serif,
+    Monospace,
+    Name(String),
+}
+
+impl Family {
+    /// The family drawn in the designer, which is the default one for
+    /// names that are still being typed.
+    fn to_iced(&self) -> font::Family {
+        match self {
+            Self::SansSerif => font::Family::SansSerif,
+            Self::Serif => font::Family::Serif,
+            Self::Monospace => font::Family::Monospace,
+            Self::Name(name) => interned(name)
+                .map_or(iced::Font::DEFAULT.family, font::Family::Name),
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            Self::SansSerif => String::from("iced::font::Family::SansSerif"),
+            Self::Serif => String::from("iced::font::Family::Serif"),
+            Self::Monospace => String::from("iced::font::Family::Monospace"),
+            Self::Name(name) => format!("iced::font::Family::Name({name:?})"),
+        }
+    }
+}
+
+impl From<String> for Family {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "sans-serif" => Self::SansSerif,
+            "serif" => Self::Serif,
+            "monospace" => Self::Monospace,
+            // Names read from a project are done being typed
+            _ => {
+                let _ = intern(&name);
+
+                Self::Name(name)
+            }
+        }
+    }
+}
+
+impl From<Family> for String {
+    fn from(family: Family) -> Self {
+        match family {
+            Family::SansSerif => String::from("sans-serif"),
+            Family::Serif => String::from("serif"),
+            Family::Monospace => String::from("monospace"),
+            Family::Name(name) => name,
+        }
+    }
+}
+
+/// The family names given to iced so far.
+static NAMES: LazyLock<Mutex<BTreeSet<&'static str>>> =
+    LazyLock::new(Mutex::default);
+
+/// Gives family names the `'static` lifetime iced needs, leaking every
+/// name once.
+///
+/// Only names that are done being typed are interned, so that typing one
+/// doesn't leak each of its prefixes.
+fn intern(name: &str) -> &'static str {
+    let mut names = NAMES.lock().unwrap_or_else(|error| error.into_inner());
+
+    match names.get(name) {
+        Some(name) => name,
+        None => {
+            let name: &'static str =
+                Box::leak(name.to_owned().into_boxed_str());
+            let _ = names.insert(name);
+
+            name
+        }
+    }
+}
+
+/// The interned `name`, if it was committed.
+fn interned(name: &str) -> Option<&'static str> {
+    NAMES
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .get(name)
+        .copied()
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum Weight {
+    Thin,
+    ExtraLight,
+    Light,
+    #[default]
+    Normal,
+    Medium,
+    Semibold,
+    Bold,
+    ExtraBold,
+    Black,
+}
+
+impl Weight {
+    pub const ALL: &'static [Self] = &[
+        Self::Thin,
+        Self::ExtraLight,
+        Self::Light,
+        Self::Normal,
+        Self::Medium,
+        Self::Semibold,
+        Self::Bold,
+        Self::ExtraBold,
+        Self::Black,
+    ];
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl From<Weight> for font::Weight {
+    fn from(weight: Weight) -> Self {
+        match weight {
+            Weight::Thin => Self::Thin,
+            Weight::ExtraLight => Self::ExtraLight,
+            Weight::Light => Self::Light,
+            Weight::Normal => Self::Normal,
+            Weight::Medium => Self::Medium,
+            Weight::Semibold => Self::Semibold,
+            Weight::Bold => Self::Bold,
+            Weight::ExtraBold => Self::ExtraBold,
+            Weight::Black => Self::Black,
+        }
+    }
+}
+
+impl std::fmt::Display for Weight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Thin => "Thin",
+            Self::ExtraLight => "Extra light",
+            Self::Light => "Light",
+            Self::Normal => "Normal",
+            Self::Medium => "Medium",
+            Self::Semibold => "Semibold",
+            Self::Bold => "Bold",
+            Self::ExtraBold => "Extra bold",
+            Self::Black => "Black",
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum Style {
+    #[default]
+    Normal,
+    Italic,
+    Oblique,
+}
+
+impl Style {
+    pub const ALL: &'static [Self] =
+        &[Self::Normal, Self::Italic, Self::Oblique];
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl From<Style> for font::Style {
+    fn from(style: Style) -> Self {
+        match style {
+            Style::Normal => Self::Normal,
+            Style::Italic => Self::Italic,
+            Style::Oblique => Self::Oblique,
+        }
+    }
+}
+
+impl std::fmt::Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Normal => "Normal",
+            Self::Italic => "Italic",
+            Self::Oblique => "Oblique",
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum Stretch {
+    UltraCondensed,
+    ExtraCondensed,
+    Condensed,
+    SemiCondensed,
+    #[default]
+    Normal,
+    SemiExpanded,
+    Expanded,
+    ExtraExpanded,
+    UltraExpanded,
+}
+
+impl Stretch {
+    pub const ALL: &'static [Self] = &[
+        Self::UltraCondensed,
+        Self::ExtraCondensed,
+        Self::Condensed,
+        Self::SemiCondensed,
+        Self::Normal,
+        Self::SemiExpanded,
+        Self::Expanded,
+        Self::ExtraExpanded,
+        Self::UltraExpanded,
+    ];
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl From<Stretch> for font::Stretch {
+    fn from(stretch: Stretch) -> Self {
+        match stretch {
+            Stretch::UltraCondensed => Self::UltraCondensed,
+            Stretch::ExtraCondensed => Self::ExtraCondensed,
+            Stretch::Condensed => Self::Condensed,
+            Stretch::SemiCondensed => Self::SemiCondensed,
+            Stretch::Normal => Self::Normal,
+            Stretch::SemiExpanded => Self::SemiExpanded,
+            Stretch::Expanded => Self::Expanded,
+            Stretch::ExtraExpanded => Self::ExtraExpanded,
+            Stretch::UltraExpanded => Self::UltraExpanded,
+        }
+    }
+}
+
+impl std::fmt::Display for Stretch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::UltraCondensed => "Ultra condensed",
+            Self::ExtraCondensed => "Extra condensed",
+            Self::Condensed => "Condensed",
+            Self::SemiCondensed => "Semi condensed",
+            Self::Normal => "Normal",
+            Self::SemiExpanded => "Semi expanded",
+            Self::Expanded => "Expanded",
+            Self::ExtraExpanded => "Extra expanded",
+            Self::UltraExpanded => "Ultra expanded",
+        })
+    }
+}
+
+/// The font of a text or button label, using the family of
+/// `iced::Font::DEFAULT` when none is set.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ElementFont {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family: Option<Family>,
+    #[serde(default, skip_serializing_if = "Weight::is_default")]
+    pub weight: Weight,
+    #[serde(default, skip_serializing_if = "Style::is_default")]
+    pub style: Style,
+    #[serde(default, skip_serializing_if = "Stretch::is_default")]
+    pub stretch: Stretch,
+    /// Whether to shape text with the slower, complete shaper needed by
+    /// some scripts and ligatures.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub advanced_shaping: bool,
+}
+
+impl ElementFont {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether `other` only has another family name, as while it's typed.
+    pub fn is_renamed_to(&self, other: &Self) -> bool {
+        matches!(
+            (&self.family, &other.family),
+            (Some(Family::Name(_)), Some(Family::Name(_)))
+        ) && Self {
+            family: None,
+            ..self.clone()
+        } == Self {
+            family: None,
+            ..other.clone()
+        }
+    }
+
+    fn has_font(&self) -> bool {
+        self.family.is_some()
+            || !self.weight.is_default()
+            || !self.style.is_default()
+            || !self.stretch.is_default()
+    }
+
+    /// The font to draw with, if it differs from the default one.
+    pub fn font(&self) -> Option<iced::Font> {
+        self.has_font().then(|| iced::Font {
+            family: self
+                .family
+                .as_ref()
+                .map_or(iced::Font::DEFAULT.family, Family::to_iced),
+            weight: self.weight.into(),
+            style: self.style.into(),
+            stretch: self.stretch.into(),
+        })
+    }
+
+    pub fn shaping(&self) -> Shaping {
+        if self.advanced_shaping {
+            Shaping::Advanced
+        } else {
+            Shaping::default()
+        }
+    }
+
+    /// Makes the family name usable in the designer, once it's done being
+    /// typed.
+    pub fn commit_family(&self) {
+        if let Some(Family::Name(name)) = &self.family
+            && !name.is_empty()
+        {
+            let _ = intern(name);
+        }
+    }
+
+    /// The expression building the font, if it differs from the default
+    /// one.
+    pub fn font_code(&self) -> Option<String> {
+        if !self.has_font() {
+            return None;
+        }
+
+        let mut fields = Vec::new();
+
+        if let Some(family) = &self.family {
+            fields.push(format!("family: {}", family.code()));
+        }
+        if !self.weight.is_default() {
+            fields
+                .push(format!("weight: iced::font::Weight::{:?}", self.weight));
+        }
+        if !self.style.is_default() {
+            fields.push(format!("style: iced::font::Style::{:?}", self.style));
+        }
+        if !self.stretch.is_default() {
+            fields.push(format!(
+                "stretch: iced::font::Stretch::{:?}",
+                self.stretch
+            ));
+        }
+
+        Some(format!(
+            "iced::Font {{ {}, ..iced::Font::DEFAULT }}",
+            fields.join(", ")
+        ))
+    }
+
+    /// The `.font(..)` and `.shaping(..)` calls of a text.
+    pub fn code(&self) -> String {
+        let mut code = self
+            .font_code()
+            .map(|font| format!(".font({font})"))
+            .unwrap_or_default();
+
+        if self.advanced_shaping {
+            code = format!(
+                "{code}.shaping(iced::widget::text::Shaping::Advanced)"
+            );
+        }
+
+        code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_font_has_no_code() {
+        assert_eq!(ElementFont::default().code(), "");
+        assert_eq!(ElementFont::default().font(), None)
+    }
+
+    #[test]
+    fn named_families_are_shaped_as_set() {
+        let font = ElementFont {
+            family: Some(Family::Name(String::from("Fira Sans"))),
+            weight: Weight::Bold,
+            advanced_shaping: true,
+            ..ElementFont::default()
+        };
+
+        assert_eq!(
+            font.code(),
+            ".font(iced::Font { family: iced::font::Family::Name(\"Fira Sans\"), weight: iced::font::Weight::Bold, ..iced::Font::DEFAULT }).shaping(iced::widget::text::Shaping::Advanced)"
+        )
+    }
+
+    #[test]
+    fn families_are_stored_as_names() {
+        let font = ElementFont {
+            family: Some(Family::Monospace),
+            style: Style::Italic,
+            ..ElementFont::default()
+        };
+        let json = serde_json::to_value(&font).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({ "family": "monospace", "style": "Italic" })
+        );
+        assert_eq!(serde_json::from_value::<ElementFont>(json).unwrap(), font)
+    }
+
+    #[test]
+    fn names_are_interned() {
+        assert!(std::ptr::eq(
+            intern("Inter"),
+            intern(&String::from("Inter"))
+        ))
+    }
+
+    #[test]
+    fn names_being_typed_are_not_interned() {
+        let font = ElementFont {
+            family: Some(Family::Name(String::from("Fira Co"))),
+            ..ElementFont::default()
+        };
+
+        assert_eq!(font.font().unwrap().family, iced::Font::DEFAULT.family);
+        assert_eq!(interned("Fira Co"), None);
+
+        font.commit_family();
+
+        assert_eq!(font.font().unwrap().family, font::Family::Name("Fira Co"))
+    }
+}
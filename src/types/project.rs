@@ -1,19 +1,121 @@
+mod format;
+mod migrations;
+mod palette;
+mod view;
+
 use std::path::{Path, PathBuf};
 
 extern crate fxhash;
 use iced::Theme;
+use iced::advanced::widget::Id;
 use rust_format::{Edition, Formatter, RustFmt};
 use serde::{Deserialize, Serialize};
 
-use super::rendered_element::RenderedElement;
+use self::format::Format;
+pub(crate) use self::palette::hex;
+pub use self::palette::{CustomPalette, PaletteColor, color_code, parse_color};
+pub use self::view::View;
+use super::rendered_element::{RenderedElement, component};
 use crate::Error;
 use crate::appearance::iced_theme_from_str;
+use crate::config::Config;
+use crate::types::ElementName;
+use crate::types::asset::{self, AssetKind, AssetMode};
+use crate::types::component::{Codegen, Param, Signature};
+use crate::types::span;
+
+const PROJECT_FILTER: &str = "*.json, *.ron, *.toml";
+/// Changed lines shown when comparing with a modified project file.
+const MAX_DIFF_LINES: usize = 30;
+
+/// Hashes the contents of a project file, to notice when it changes on disk.
+pub fn content_hash(contents: &str) -> u64 {
+    fxhash::hash64(contents)
+}
+
+/// Hashes the project file at `path`, if it can be read.
+pub async fn file_hash(path: PathBuf) -> Option<u64> {
+    tokio::fs::read_to_string(path)
+        .await
+        .ok()
+        .map(|contents| content_hash(&contents))
+}
+
+/// The initial size of the generated app's window.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowSize {
+    pub width: f32,
+    pub height: f32,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     pub title: Option<String>,
     pub theme: Option<String>,
-    pub element_tree: Option<RenderedElement>,
+    /// Colors of a custom theme, used instead of `theme` when set.
+    #[serde(default)]
+    pub palette: Option<CustomPalette>,
+    #[serde(default)]
+    pub window_size: Option<WindowSize>,
+    /// Family name of the font used by default in the generated app.
+    #[serde(default)]
+    pub default_font: Option<String>,
+    #[serde(default)]
+    pub text_size: Option<f32>,
+    /// The screens of the app, there's always at least one.
+    views: Vec<View>,
+    /// The view shown in the designer, not saved.
+    #[serde(skip)]
+    current_view: usize,
+    /// Element trees shared by several instances, named like views.
+    #[serde(default)]
+    components: Vec<View>,
+    /// The component shown in the designer instead of the current view.
+    #[serde(skip)]
+    editing_component: Option<usize>,
+    /// Images and SVGs available to the project, relative to the project file.
+    #[serde(default)]
+    pub assets: Vec<PathBuf>,
+    #[serde(default)]
+    pub asset_mode: AssetMode,
+}
+
+/// Writes `contents` to a temporary file next to `path` and moves it in place
+/// afterwards, so a crash can never leave a half-written file behind.
+///
+/// If `backup` is set, the previous version of the file is kept as `.bak`.
+async fn write_atomically(
+    path: &Path,
+    contents: &str,
+    backup: bool,
+) -> Result<(), Error> {
+    use tokio::fs;
+
+    let file_name = path
+        .file_name()
+        .ok_or("The project path doesn't point to a file")?
+        .to_string_lossy();
+    let temp_path = path.with_file_name(format!(".{file_name}.tmp"));
+
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let mut file = fs::File::create(&temp_path).await?;
+        file.write_all(contents.as_bytes()).await?;
+        file.sync_all().await?;
+    }
+
+    if backup && path.is_file() {
+        let _ = fs::copy(path, path.with_file_name(format!("{file_name}.bak")))
+            .await?;
+    }
+
+    if let Err(error) = fs::rename(&temp_path, path).await {
+        let _ = fs::remove_file(&temp_path).await;
+        return Err(error.into());
+    }
+
+    Ok(())
 }
 
 impl Default for Project {
@@ -27,28 +129,297 @@ impl Project {
         Self {
             title: None,
             theme: None,
-            element_tree: None,
+            palette: None,
+            window_size: None,
+            default_font: None,
+            text_size: None,
+            views: vec![View::new("Main")],
+            current_view: 0,
+            components: Vec::new(),
+            editing_component: None,
+            assets: Vec::new(),
+            asset_mode: AssetMode::default(),
         }
     }
 
     pub fn get_theme(&self) -> Theme {
+        if let Some(palette) = &self.palette {
+            return palette.theme(self.custom_theme_name());
+        }
+
         match &self.theme {
             Some(theme) => iced_theme_from_str(theme),
             None => iced::Theme::default(),
         }
     }
 
-    pub async fn from_path(path: PathBuf) -> Result<(PathBuf, Self), Error> {
+    fn custom_theme_name(&self) -> &str {
+        self.title.as_deref().unwrap_or("Custom")
+    }
+
+    /// The name of the project's theme, if it doesn't match any of iced's
+    /// themes and the default one is used instead.
+    pub fn unknown_theme(&self) -> Option<&str> {
+        self.theme.as_deref().filter(|name| {
+            self.palette.is_none()
+                && !Theme::ALL.iter().any(|theme| theme.to_string() == *name)
+        })
+    }
+
+    pub fn views(&self) -> &[View] {
+        &self.views
+    }
+
+    pub fn current_view(&self) -> usize {
+        self.current_view
+    }
+
+    pub fn select_view(&mut self, index: usize) {
+        if index < self.views.len() {
+            self.current_view = index;
+            self.editing_component = None;
+        }
+    }
+
+    /// Adds an empty view with an unused name and selects it.
+    pub fn add_view(&mut self) {
+        let name = (self.views.len() + 1..)
+            .map(|number| format!("View {number}"))
+            .find(|name| self.views.iter().all(|view| view.name != *name))
+            .expect("an unused view name");
+
+        self.views.push(View::new(name));
+        self.current_view = self.views.len() - 1;
+        self.editing_component = None;
+    }
+
+    pub fn rename_view(&mut self, name: String) {
+        self.views[self.current_view].name = name;
+    }
+
+    /// Removes the current view, unless it's the only one.
+    pub fn remove_view(&mut self) {
+        if self.views.len() > 1 {
+            let _ = self.views.remove(self.current_view);
+            self.current_view = self.current_view.min(self.views.len() - 1);
+        }
+    }
+
+    /// Whether none of the views has any elements yet.
+    pub fn is_blank(&self) -> bool {
+        self.views.iter().all(|view| view.element_tree.is_none())
+    }
+
+    pub fn components(&self) -> &[View] {
+        &self.components
+    }
+
+    /// The index of the component shown in the designer, if any.
+    pub fn editing_component(&self) -> Option<usize> {
+        self.editing_component
+    }
+
+    pub fn edit_component(&mut self, index: usize) {
+        if index < self.components.len() {
+            self.editing_component = Some(index);
+        }
+    }
+
+    pub fn stop_editing_component(&mut self) {
+        self.editing_component = None;
+    }
+
+    /// Replaces the element with `id` in the current view by an instance of
+    /// a new component named `name`, made from the element and its children.
+    pub fn promote_to_component(
+        &mut self,
+        id: &Id,
+        name: &str,
+    ) -> Result<(), Error> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err("Components need a name".into());
+        }
+        if self.editing_component.is_some() {
+            return Err(
+                "Components can't be made inside other components".into()
+            );
+        }
+        if self
+            .components
+            .iter()
+            .any(|component| component.name == name)
+        {
+            return Err(
+                format!("There already is a component called {name}").into()
+            );
+        }
+
+        let element = self
+            .element_tree_mut()
+            .as_mut()
+            .and_then(|element_tree| element_tree.find_by_id(id))
+            .ok_or(Error::NonExistentElement)?;
+
+        if element.any(&|name| matches!(name, ElementName::Component(_))) {
+            return Err(
+                "Components can't contain instances of other components".into(),
+            );
+        }
+
+        let master = std::mem::replace(element, component(name));
+        self.components.push(View {
+            name: name.to_owned(),
+            element_tree: Some(master),
+        });
+
+        Ok(())
+    }
+
+    /// The view or component shown in the designer.
+    fn current(&self) -> &View {
+        match self.editing_component {
+            Some(index) => &self.components[index],
+            None => &self.views[self.current_view],
+        }
+    }
+
+    /// The element tree of the current view, or of the component being
+    /// edited.
+    pub fn element_tree(&self) -> Option<&RenderedElement> {
+        self.current().element_tree.as_ref()
+    }
+
+    pub fn element_tree_mut(&mut self) -> &mut Option<RenderedElement> {
+        match self.editing_component {
+            Some(index) => &mut self.components[index].element_tree,
+            None => &mut self.views[self.current_view].element_tree,
+        }
+    }
+
+    /// The element trees of all views and components.
+    pub fn element_trees(&self) -> impl Iterator<Item = &RenderedElement> {
+        self.views
+            .iter()
+            .chain(&self.components)
+            .filter_map(|view| view.element_tree.as_ref())
+    }
+
+    pub fn element_trees_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut RenderedElement> {
+        self.views
+            .iter_mut()
+            .chain(&mut self.components)
+            .filter_map(|view| view.element_tree.as_mut())
+    }
+
+    /// Returns every asset path the project refers to, both from its
+    /// library and its views.
+    pub fn referenced_assets(&self) -> Vec<&Path> {
+        let mut paths: Vec<&Path> =
+            self.assets.iter().map(PathBuf::as_path).collect();
+
+        for element_tree in self.element_trees() {
+            paths.extend(
+                element_tree
+                    .asset_paths()
+                    .into_iter()
+                    .filter(|path| !path.is_empty())
+                    .map(Path::new),
+            );
+        }
+
+        paths
+    }
+
+    /// The fonts bundled with the project.
+    pub fn font_assets(&self) -> impl Iterator<Item = &Path> {
+        self.assets
+            .iter()
+            .map(PathBuf::as_path)
+            .filter(|path| AssetKind::from_path(path) == Some(AssetKind::Font))
+    }
+
+    /// Makes the project's asset paths relative to `to` instead of `from`.
+    ///
+    /// Without a `to` the paths are made absolute.
+    pub fn rebase_assets(&mut self, from: Option<&Path>, to: Option<&Path>) {
+        let rebase = |path: &Path| {
+            let resolved = asset::resolve(path, from);
+            let resolved = std::path::absolute(&resolved).unwrap_or(resolved);
+
+            asset::relativize(&resolved, to)
+        };
+
+        for path in &mut self.assets {
+            *path = rebase(path);
+        }
+
+        for element_tree in self.element_trees_mut() {
+            for path in element_tree.asset_paths_mut() {
+                if !path.is_empty() {
+                    *path = rebase(Path::new(path.as_str()))
+                        .to_string_lossy()
+                        .into_owned();
+                }
+            }
+        }
+    }
+
+    /// Reads a project from a JSON string, like the built-in templates.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        Self::from_document(serde_json::from_str(json)?)
+    }
+
+    /// Whether `path` has the extension of a supported project format.
+    pub fn is_project_file(path: &Path) -> bool {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| Format::EXTENSIONS.contains(&extension))
+    }
+
+    fn from_document(document: serde_json::Value) -> Result<Self, Error> {
+        let mut project: Self =
+            serde_json::from_value(migrations::migrate(document)?)?;
+
+        if project.views.is_empty() {
+            project.views.push(View::new("Main"));
+        }
+
+        for element_tree in project.element_trees_mut() {
+            element_tree.restore_options();
+        }
+
+        Ok(project)
+    }
+
+    fn to_document(&self) -> Result<serde_json::Value, Error> {
+        let mut document = serde_json::to_value(self)?;
+        migrations::stamp(&mut document);
+
+        Ok(document)
+    }
+
+    /// Loads the project at `path`, along with the hash of the file.
+    pub async fn from_path(
+        path: PathBuf,
+    ) -> Result<(PathBuf, u64, Self), Error> {
         let contents = tokio::fs::read_to_string(&path).await?;
-        let project: Self = serde_json::from_str(&contents)?;
+        let document = Format::from_path(&path).parse(&contents)?;
 
-        Ok((path, project))
+        Ok((
+            path,
+            content_hash(&contents),
+            Self::from_document(document)?,
+        ))
     }
 
-    pub async fn from_file() -> Result<(PathBuf, Self), Error> {
+    pub async fn from_file() -> Result<(PathBuf, u64, Self), Error> {
         let picked_file = rfd::AsyncFileDialog::new()
-            .set_title("Open a JSON file...")
-            .add_filter("*.json, *.JSON", &["json", "JSON"])
+            .set_title("Open a project file...")
+            .add_filter(PROJECT_FILTER, Format::EXTENSIONS)
             .pick_file()
             .await
             .ok_or(Error::DialogClosed)?;
@@ -58,10 +429,21 @@ impl Project {
         Self::from_path(path).await
     }
 
+    /// Serializes the project in the format matching the extension of `path`.
+    fn to_contents(&self, path: &Path) -> Result<String, Error> {
+        Format::from_path(path).serialize(&self.to_document()?)
+    }
+
+    /// Writes the project to `path`, or a file picked by the user, returning
+    /// where it ended up along with the hash of the written file.
+    ///
+    /// Asset paths are rebased from `previous_path` if the project ends up
+    /// in a different location.
     pub async fn write_to_file(
-        self,
+        mut self,
         path: Option<PathBuf>,
-    ) -> Result<PathBuf, Error> {
+        previous_path: Option<PathBuf>,
+    ) -> Result<(PathBuf, u64), Error> {
         use tokio::fs;
 
         let path = if let Some(p) = path {
@@ -74,8 +456,8 @@ impl Project {
             p
         } else {
             rfd::AsyncFileDialog::new()
-                .set_title("Save to JSON file...")
-                .add_filter("*.json, *.JSON", &["json", "JSON"])
+                .set_title("Save to project file...")
+                .add_filter(PROJECT_FILTER, Format::EXTENSIONS)
                 .save_file()
                 .await
                 .as_ref()
@@ -84,74 +466,435 @@ impl Project {
                 .ok_or(Error::DialogClosed)?
         };
 
-        let contents = serde_json::to_string(&self)?;
-        fs::write(&path, contents).await?;
+        if previous_path.as_ref() != Some(&path) {
+            self.rebase_assets(previous_path.as_deref(), Some(&path));
+        }
+
+        let contents = self.to_contents(&path)?;
+        write_atomically(&path, &contents, true).await?;
+
+        Ok((path, content_hash(&contents)))
+    }
+
+    /// Shows how the project file at `path` differs from this project.
+    pub async fn diff_with_file(self, path: PathBuf) -> Result<String, Error> {
+        let on_disk = tokio::fs::read_to_string(&path).await?;
+
+        Ok(crate::diff::render(
+            &on_disk,
+            &self.to_contents(&path)?,
+            MAX_DIFF_LINES,
+        ))
+    }
 
-        Ok(path)
+    /// Stores a copy of the given projects in the config directory, along
+    /// with the paths they were last saved to, so unsaved changes survive a
+    /// crash.
+    pub async fn autosave(
+        documents: Vec<(Option<PathBuf>, Self)>,
+    ) -> Result<(), Error> {
+        let documents = documents
+            .into_iter()
+            .map(|(project_path, project)| {
+                Ok(serde_json::json!({
+                    "project_path": project_path,
+                    "project": project.to_document()?,
+                }))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        write_atomically(
+            &Config::autosave_file_path(),
+            &Format::Json
+                .serialize(&serde_json::json!({ "documents": documents }))?,
+            false,
+        )
+        .await
+    }
+
+    /// Loads the autosaved projects, along with the paths they were last
+    /// saved to.
+    pub async fn recover() -> Result<Vec<(Option<PathBuf>, Self)>, Error> {
+        let contents =
+            tokio::fs::read_to_string(Config::autosave_file_path()).await?;
+        let mut autosave: serde_json::Value = serde_json::from_str(&contents)?;
+
+        // Older autosaves hold a single project at the top level
+        let documents = match autosave.get_mut("documents") {
+            Some(serde_json::Value::Array(documents)) => {
+                std::mem::take(documents)
+            }
+            _ => vec![autosave],
+        };
+
+        documents
+            .into_iter()
+            .map(|mut document| {
+                let project_path = document
+                    .get("project_path")
+                    .and_then(serde_json::Value::as_str)
+                    .map(PathBuf::from);
+                let project = document
+                    .get_mut("project")
+                    .map(serde_json::Value::take)
+                    .ok_or("The autosave file doesn't contain a project")?;
+
+                Ok((project_path, Self::from_document(project)?))
+            })
+            .collect()
+    }
+
+    pub fn has_autosave() -> bool {
+        Config::autosave_file_path().is_file()
+    }
+
+    pub async fn discard_autosave() -> Result<(), Error> {
+        match tokio::fs::remove_file(Config::autosave_file_path()).await {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => {
+                Err(error.into())
+            }
+            _ => Ok(()),
+        }
     }
 
     pub fn app_code(&mut self) -> Result<String, Error> {
         use iced::debug;
         let codegen = debug::time("Code Generation");
 
-        let result = match self.element_tree {
-            Some(ref element_tree) => {
-                let (imports, view) = element_tree.codegen();
-                let theme = self.get_theme();
+        let result = if self.is_blank() {
+            Err("No element tree present".into())
+        } else if self.views.len() == 1 {
+            format_code(&self.single_view_code())
+        } else {
+            format_code(&self.multi_view_code())
+        };
+
+        codegen.finish();
+        result
+    }
+
+    /// The title of the app, and the code creating its theme.
+    fn title_and_theme(&self) -> (&str, String) {
+        let theme = match &self.palette {
+            Some(palette) => palette.code(self.custom_theme_name()),
+            // The names of some themes aren't valid identifiers
+            None => format!("iced::Theme::{:?}", self.get_theme()),
+        };
+
+        (self.title.as_deref().unwrap_or("New app"), theme)
+    }
+
+    /// The calls configuring `iced::application` beyond title and theme.
+    fn application_settings(&self) -> String {
+        let mut settings = String::new();
+
+        if let Some(text_size) = self.text_size {
+            settings = format!(
+                "{settings}.settings(iced::Settings {{ default_text_size: iced::Pixels({text_size:?}), ..Default::default() }})"
+            );
+        }
+        if let Some(font) = &self.default_font {
+            settings = format!(
+                "{settings}.default_font(iced::Font::with_name({font:?}))"
+            );
+        }
+        if let Some(WindowSize { width, height }) = self.window_size {
+            settings = format!(
+                "{settings}.window_size(iced::Size::new({width:?}, {height:?}))"
+            );
+        }
+        // Bundled fonts are always embedded, as the builder needs the bytes
+        for path in self.font_assets() {
+            settings =
+                format!("{settings}.font(include_bytes!({path:?}).as_slice())");
+        }
+
+        settings
+    }
+
+    /// Whether a rich text of the project links somewhere.
+    fn has_links(&self) -> bool {
+        self.views
+            .iter()
+            .chain(&self.components)
+            .filter_map(|view| view.element_tree.as_ref())
+            .any(|element_tree| {
+                element_tree.any(&|name| {
+                    matches!(name, ElementName::RichText(spans) if span::has_links(spans))
+                })
+            })
+    }
+
+    /// Describes the function generated for every component, with a
+    /// parameter for each value an instance overrides.
+    fn component_signatures(&self) -> Vec<Signature> {
+        let instances: Vec<&RenderedElement> = self
+            .views
+            .iter()
+            .filter_map(|view| view.element_tree.as_ref())
+            .flat_map(RenderedElement::instances)
+            .collect();
+        let mut functions = Vec::new();
+
+        self.components
+            .iter()
+            .map(|component| {
+                let params = component
+                    .element_tree
+                    .iter()
+                    .flat_map(RenderedElement::override_slots)
+                    .filter(|slot| {
+                        instances.iter().any(|instance| {
+                            instance.component_name()
+                                == Some(component.name.as_str())
+                                && instance.overrides().contains_key(&slot.key)
+                        })
+                    })
+                    .map(|slot| Param::new(&slot))
+                    .collect();
+
+                Signature {
+                    name: component.name.clone(),
+                    function: unique(
+                        component.component_function_name(),
+                        &mut functions,
+                    ),
+                    params,
+                }
+            })
+            .collect()
+    }
+
+    /// Generates the functions of all components, returning their imports
+    /// and definitions.
+    fn component_code(&self, signatures: &[Signature]) -> (String, String) {
+        let mut imports = String::new();
+        let mut definitions = String::new();
+
+        for (component, signature) in self.components.iter().zip(signatures) {
+            let Some(element_tree) = &component.element_tree else {
+                continue;
+            };
+
+            let (component_imports, body) = element_tree.codegen(&Codegen {
+                params: &signature.params,
+                ..Codegen::new(self.asset_mode, signatures)
+            });
+
+            imports.push_str(&component_imports);
+            definitions =
+                format!("{definitions}\n{}\n", signature.definition(&body));
+        }
+
+        (imports, definitions)
+    }
+
+    fn single_view_code(&self) -> String {
+        let signatures = self.component_signatures();
+        let (component_imports, components) = self.component_code(&signatures);
+        let (imports, view) = self.views[0]
+            .element_tree
+            .as_ref()
+            .map(|element_tree| {
+                element_tree
+                    .codegen(&Codegen::new(self.asset_mode, &signatures))
+            })
+            .unwrap_or_default();
+        let imports = merge_imports([imports, component_imports]);
+        let (title, theme) = self.title_and_theme();
+        let settings = self.application_settings();
+        let (link_variant, update) = if self.has_links() {
+            (
+                LINK_VARIANT,
+                format!(
+                    "fn update(&mut self, message: Message) {{ match message {{ {LINK_ARM} }} }}"
+                ),
+            )
+        } else {
+            (
+                "",
+                String::from(
+                    "fn update(&mut self, _message: Message) {\n        // Insert your desired update logic here\n    }",
+                ),
+            )
+        };
 
-                let app_code = format!(
-                    r#"// Automatically generated by iced Builder
+        format!(
+            r#"// Automatically generated by iced Builder
 use iced::{{widget::{{{imports}}},Element}};
 
 fn main() -> iced::Result {{
-    iced::application(State::default, State::update, State::view).title("{title}").theme(State::theme).run()
+    iced::application(State::default, State::update, State::view).title({title:?}).theme(State::theme){settings}.run()
 }}
 
 #[derive(Default)]
 struct State;
 
 #[derive(Debug, Clone)]
-enum Message {{}}
+enum Message {{{link_variant}}}
 
 impl State {{
-    fn update(&mut self, _message: Message) {{
-        // Insert your desired update logic here
-    }}
+    {update}
 
     fn theme(&self) -> iced::Theme {{
-        iced::Theme::{theme}
+        {theme}
     }}
 
     fn view(&self) -> Element<Message> {{
         {view}.into()
     }}
-}}"#,
-                    title = match self.title {
-                        Some(ref t) => t,
-                        None => "New app",
-                    },
-                    theme = theme.to_string().replace(" ", "")
-                );
-
-                let config = rust_format::Config::new_str()
-                    .edition(Edition::Rust2021)
-                    .option("trailing_comma", "Never")
-                    .option("imports_granularity", "Crate");
-                let rustfmt = RustFmt::from_config(config);
-
-                match rustfmt.format_str(&app_code) {
-                    Ok(code) => return Ok(code),
-                    // rustfmt is missing, that's fine, we fall back to manual formatting
-                    Err(rust_format::Error::IOError(_)) => {
-                        return Ok(app_code);
-                    }
-                    Err(err) => return Err(err.into()),
+}}
+{components}"#
+        )
+    }
+
+    /// Every view gets its own function, with a `Screen` enum to switch
+    /// between them.
+    fn multi_view_code(&self) -> String {
+        let mut imports = Vec::new();
+        let mut variants: Vec<String> = Vec::new();
+        let mut functions: Vec<String> = Vec::new();
+        let mut view_functions = String::new();
+        let mut arms = String::new();
+        let signatures = self.component_signatures();
+        let (component_imports, components) = self.component_code(&signatures);
+        imports.push(component_imports);
+
+        for view in &self.views {
+            let variant = unique(view.variant_name(), &mut variants);
+            let function = unique(view.function_name(), &mut functions);
+
+            let body = match &view.element_tree {
+                Some(element_tree) => {
+                    let (view_imports, body) = element_tree
+                        .codegen(&Codegen::new(self.asset_mode, &signatures));
+                    imports.push(view_imports);
+                    body
                 }
-            }
-            None => Err("No element tree present".into()),
+                None => {
+                    imports.push(String::from("horizontal_space,"));
+                    String::from("horizontal_space()")
+                }
+            };
+
+            arms = format!("{arms}Screen::{variant} => self.{function}(),\n");
+            view_functions = format!(
+                "{view_functions}\n    fn {function}(&self) -> Element<Message> {{\n        {body}.into()\n    }}\n"
+            );
+        }
+
+        let imports = merge_imports(imports);
+        let first = variants[0].clone();
+        let variants = variants.join(",\n    ");
+        let (title, theme) = self.title_and_theme();
+        let settings = self.application_settings();
+        let (link_variant, link_arm) = if self.has_links() {
+            (LINK_VARIANT, LINK_ARM)
+        } else {
+            ("", "")
         };
 
-        codegen.finish();
-        result
+        format!(
+            r#"// Automatically generated by iced Builder
+use iced::{{widget::{{{imports}}},Element}};
+
+fn main() -> iced::Result {{
+    iced::application(State::default, State::update, State::view).title({title:?}).theme(State::theme){settings}.run()
+}}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(dead_code)] // Shown once a button navigates to them
+enum Screen {{
+    #[default]
+    {variants},
+}}
+
+#[derive(Default)]
+struct State {{
+    screen: Screen,
+}}
+
+#[derive(Debug, Clone)]
+enum Message {{
+    /// Shows another screen, e.g. `button("Back").on_press(Message::Navigate(Screen::{first}))`
+    #[allow(dead_code)] // Until a button sends it
+    Navigate(Screen),{link_variant}
+}}
+
+impl State {{
+    fn update(&mut self, message: Message) {{
+        match message {{
+            Message::Navigate(screen) => self.screen = screen,
+            {link_arm}
+        }}
+    }}
+
+    fn theme(&self) -> iced::Theme {{
+        {theme}
+    }}
+
+    fn view(&self) -> Element<Message> {{
+        match self.screen {{
+            {arms}
+        }}
+    }}
+{view_functions}}}
+{components}"#
+        )
+    }
+}
+
+/// The message of the generated app for clicked links of rich texts.
+const LINK_VARIANT: &str = "
+    /// A link of a rich text was clicked
+    LinkClicked(&'static str),
+";
+const LINK_ARM: &str = "Message::LinkClicked(_link) => {
+    // Open the link here, e.g. with the `open` crate
+}";
+
+/// Joins the `widget` imports of several views, without duplicates.
+fn merge_imports(imports: impl IntoIterator<Item = String>) -> String {
+    let mut merged: Vec<String> = Vec::new();
+
+    for imports in imports {
+        for import in imports.split(',').filter(|import| !import.is_empty()) {
+            if !merged.iter().any(|merged| merged == import) {
+                merged.push(import.to_owned());
+            }
+        }
+    }
+
+    merged.sort();
+    merged.join(",")
+}
+
+/// Appends a number to `name` if it's already in `taken`, and takes it.
+fn unique(name: String, taken: &mut Vec<String>) -> String {
+    let name = if taken.contains(&name) {
+        (2..)
+            .map(|number| format!("{name}{number}"))
+            .find(|candidate| !taken.contains(candidate))
+            .expect("an unused name")
+    } else {
+        name
+    };
+
+    taken.push(name.clone());
+    name
+}
+
+fn format_code(code: &str) -> Result<String, Error> {
+    let config = rust_format::Config::new_str()
+        .edition(Edition::Rust2021)
+        .option("trailing_comma", "Never")
+        .option("imports_granularity", "Crate");
+    let rustfmt = RustFmt::from_config(config);
+
+    match rustfmt.format_str(code) {
+        Ok(code) => Ok(code),
+        // rustfmt is missing, that's fine, we fall back to manual formatting
+        Err(rust_format::Error::IOError(_)) => Ok(code.to_owned()),
+        Err(err) => Err(err.into()),
     }
 }
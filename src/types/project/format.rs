@@ -0,0 +1,140 @@
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::Error;
+
+/// The file formats a [`Project`](super::Project) can be stored in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Json,
+    Ron,
+    Toml,
+}
+
+impl Format {
+    pub const EXTENSIONS: &[&str] =
+        &["json", "JSON", "ron", "RON", "toml", "TOML"];
+
+    /// Picks the format based on the extension of `path`, falling back to
+    /// JSON.
+    pub fn from_path(path: &Path) -> Self {
+        match path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase)
+            .as_deref()
+        {
+            Some("ron") => Self::Ron,
+            Some("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+
+    pub fn parse(self, contents: &str) -> Result<Value, Error> {
+        Ok(match self {
+            Self::Json => serde_json::from_str(contents)?,
+            Self::Ron => ron::from_str(contents)?,
+            Self::Toml => toml::from_str(contents)?,
+        })
+    }
+
+    pub fn serialize(self, document: &Value) -> Result<String, Error> {
+        Ok(match self {
+            Self::Json => {
+                let mut contents = serde_json::to_string_pretty(document)?;
+                contents.push('\n');
+                contents
+            }
+            Self::Ron => {
+                let mut contents = ron::ser::to_string_pretty(
+                    document,
+                    ron::ser::PrettyConfig::new().indentor("  "),
+                )?;
+                contents.push('\n');
+                contents
+            }
+            Self::Toml => {
+                let mut document = document.clone();
+                strip_nulls(&mut document);
+
+                toml::to_string_pretty(&document)?
+            }
+        })
+    }
+}
+
+/// TOML has no null value, so empty fields are left out instead.
+fn strip_nulls(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, value| !value.is_null());
+            map.values_mut().for_each(strip_nulls);
+        }
+        Value::Array(values) => values.iter_mut().for_each(strip_nulls),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn document() -> Value {
+        json!({
+            "format_version": 1,
+            "title": "Test",
+            "theme": null,
+            "element_tree": {
+                "name": { "Text": "Hello" },
+                "child_elements": null,
+                "options": { "size": "16", "width": null },
+            },
+        })
+    }
+
+    #[test]
+    fn can_pick_format_by_extension() {
+        assert_eq!(Format::from_path(Path::new("a.RON")), Format::Ron);
+
+        assert_eq!(Format::from_path(Path::new("a.toml")), Format::Toml);
+
+        assert_eq!(Format::from_path(Path::new("a.json")), Format::Json);
+
+        assert_eq!(Format::from_path(Path::new("a")), Format::Json)
+    }
+
+    #[test]
+    fn can_round_trip_json() {
+        let contents = Format::Json.serialize(&document()).unwrap();
+
+        assert_eq!(Format::Json.parse(&contents).unwrap(), document())
+    }
+
+    #[test]
+    fn can_round_trip_ron() {
+        let contents = Format::Ron.serialize(&document()).unwrap();
+
+        assert_eq!(Format::Ron.parse(&contents).unwrap(), document())
+    }
+
+    #[test]
+    fn toml_leaves_out_nulls() {
+        let contents = Format::Toml.serialize(&document()).unwrap();
+
+        assert_eq!(
+            Format::Toml.parse(&contents).unwrap(),
+            json!({
+                "format_version": 1,
+                "title": "Test",
+                "element_tree": {
+                    "name": { "Text": "Hello" },
+                    "options": { "size": "16" },
+                },
+            })
+        )
+    }
+}
@@ -0,0 +1,178 @@
+//! Upgrades project files written by older versions of iced Builder.
+use serde_json::{Map, Value};
+
+use crate::Error;
+
+/// The version of the project format written by this version of iced Builder.
+pub const FORMAT_VERSION: u64 = 3;
+
+const VERSION_KEY: &str = "format_version";
+
+type Migration = fn(&mut Map<String, Value>) -> Result<(), Error>;
+
+/// The migration at index `n` upgrades a document from version `n` to `n + 1`.
+const MIGRATIONS: &[Migration] = &[v0_to_v1, v1_to_v2, v2_to_v3];
+
+/// Brings `document` up to [`FORMAT_VERSION`].
+pub fn migrate(mut document: Value) -> Result<Value, Error> {
+    let Value::Object(map) = &mut document else {
+        return Err("The project file doesn't contain a project".into());
+    };
+
+    let version = match map.get(VERSION_KEY) {
+        Some(version) => version
+            .as_u64()
+            .ok_or("The project file has an invalid format version")?,
+        None => 0,
+    };
+
+    if version > FORMAT_VERSION {
+        return Err(Error::NewerFormat(version));
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(map)?;
+    }
+
+    stamp(&mut document);
+
+    Ok(document)
+}
+
+/// Marks `document` as being of the current [`FORMAT_VERSION`].
+pub fn stamp(document: &mut Value) {
+    if let Value::Object(map) = document {
+        let _ = map.insert(VERSION_KEY.to_owned(), FORMAT_VERSION.into());
+    }
+}
+
+/// Version 0 files predate the asset library.
+fn v0_to_v1(map: &mut Map<String, Value>) -> Result<(), Error> {
+    let _ = map
+        .entry("assets")
+        .or_insert_with(|| Value::Array(Vec::new()));
+    let _ = map
+        .entry("asset_mode")
+        .or_insert_with(|| Value::String(String::from("Runtime")));
+
+    Ok(())
+}
+
+/// Version 1 files hold a single element tree instead of named views.
+fn v1_to_v2(map: &mut Map<String, Value>) -> Result<(), Error> {
+    let element_tree = map.remove("element_tree").unwrap_or(Value::Null);
+
+    let _ = map.insert(
+        "views".to_owned(),
+        serde_json::json!([{ "name": "Main", "element_tree": element_tree }]),
+    );
+
+    Ok(())
+}
+
+/// Version 2 files predate components.
+fn v2_to_v3(map: &mut Map<String, Value>) -> Result<(), Error> {
+    let _ = map
+        .entry("components")
+        .or_insert_with(|| Value::Array(Vec::new()));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn can_migrate_unversioned_document() {
+        let document = json!({
+            "title": "Test",
+            "theme": null,
+            "element_tree": null,
+        });
+
+        assert_eq!(
+            migrate(document).ok(),
+            Some(json!({
+                "format_version": FORMAT_VERSION,
+                "title": "Test",
+                "theme": null,
+                "views": [{ "name": "Main", "element_tree": null }],
+                "assets": [],
+                "asset_mode": "Runtime",
+                "components": [],
+            }))
+        )
+    }
+
+    #[test]
+    fn can_migrate_element_tree_to_views() {
+        let element_tree = json!({
+            "name": { "Text": "Hello" },
+            "child_elements": null,
+            "options": {},
+        });
+        let document = json!({
+            "format_version": 1,
+            "title": null,
+            "element_tree": element_tree,
+        });
+
+        assert_eq!(
+            migrate(document).ok(),
+            Some(json!({
+                "format_version": FORMAT_VERSION,
+                "title": null,
+                "views": [{ "name": "Main", "element_tree": element_tree }],
+                "components": [],
+            }))
+        )
+    }
+
+    #[test]
+    fn can_add_components() {
+        let document = json!({
+            "format_version": 2,
+            "title": null,
+            "views": [],
+        });
+
+        assert_eq!(
+            migrate(document).ok(),
+            Some(json!({
+                "format_version": FORMAT_VERSION,
+                "title": null,
+                "views": [],
+                "components": [],
+            }))
+        )
+    }
+
+    #[test]
+    fn keeps_current_document() {
+        let document = json!({
+            "format_version": FORMAT_VERSION,
+            "title": null,
+            "assets": ["logo.png"],
+        });
+
+        assert_eq!(migrate(document.clone()).ok(), Some(document))
+    }
+
+    #[test]
+    fn cant_migrate_newer_document() {
+        let document = json!({ "format_version": FORMAT_VERSION + 1 });
+
+        assert!(matches!(
+            migrate(document),
+            Err(Error::NewerFormat(version)) if version == FORMAT_VERSION + 1
+        ))
+    }
+
+    #[test]
+    fn cant_migrate_non_object() {
+        assert!(migrate(json!([1, 2, 3])).is_err())
+    }
+}
@@ -0,0 +1,246 @@
+use iced::Color;
+use iced::theme::Palette;
+use serde::{Deserialize, Serialize};
+
+use crate::values::Value;
+
+/// One of the colors of a [`CustomPalette`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteColor {
+    Background,
+    Text,
+    Primary,
+    Success,
+    Warning,
+    Danger,
+}
+
+impl PaletteColor {
+    pub const ALL: &'static [Self] = &[
+        Self::Background,
+        Self::Text,
+        Self::Primary,
+        Self::Success,
+        Self::Warning,
+        Self::Danger,
+    ];
+}
+
+impl std::fmt::Display for PaletteColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Background => "Background",
+            Self::Text => "Text",
+            Self::Primary => "Primary",
+            Self::Success => "Success",
+            Self::Warning => "Warning",
+            Self::Danger => "Danger",
+        })
+    }
+}
+
+/// The colors of an `iced::Theme::custom` theme for the designed app.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CustomPalette {
+    #[serde(with = "hex")]
+    pub background: Color,
+    #[serde(with = "hex")]
+    pub text: Color,
+    #[serde(with = "hex")]
+    pub primary: Color,
+    #[serde(with = "hex")]
+    pub success: Color,
+    #[serde(with = "hex")]
+    pub warning: Color,
+    #[serde(with = "hex")]
+    pub danger: Color,
+}
+
+impl From<Palette> for CustomPalette {
+    fn from(palette: Palette) -> Self {
+        Self {
+            background: palette.background,
+            text: palette.text,
+            primary: palette.primary,
+            success: palette.success,
+            warning: palette.warning,
+            danger: palette.danger,
+        }
+    }
+}
+
+impl From<CustomPalette> for Palette {
+    fn from(palette: CustomPalette) -> Self {
+        Self {
+            background: palette.background,
+            text: palette.text,
+            primary: palette.primary,
+            success: palette.success,
+            warning: palette.warning,
+            danger: palette.danger,
+        }
+    }
+}
+
+impl CustomPalette {
+    pub fn get(&self, color: PaletteColor) -> Color {
+        match color {
+            PaletteColor::Background => self.background,
+            PaletteColor::Text => self.text,
+            PaletteColor::Primary => self.primary,
+            PaletteColor::Success => self.success,
+            PaletteColor::Warning => self.warning,
+            PaletteColor::Danger => self.danger,
+        }
+    }
+
+    pub fn set(&mut self, color: PaletteColor, value: Color) {
+        *match color {
+            PaletteColor::Background => &mut self.background,
+            PaletteColor::Text => &mut self.text,
+            PaletteColor::Primary => &mut self.primary,
+            PaletteColor::Success => &mut self.success,
+            PaletteColor::Warning => &mut self.warning,
+            PaletteColor::Danger => &mut self.danger,
+        } = value;
+    }
+
+    pub fn theme(&self, name: &str) -> iced::Theme {
+        iced::Theme::custom(name.to_owned(), (*self).into())
+    }
+
+    /// The code building the theme called `name` from the palette.
+    pub fn code(&self, name: &str) -> String {
+        let fields: Vec<String> = PaletteColor::ALL
+            .iter()
+            .map(|color| {
+                format!(
+                    "{}: {}",
+                    color.to_string().to_lowercase(),
+                    color_code(self.get(*color))
+                )
+            })
+            .collect();
+
+        format!(
+            "iced::Theme::custom(String::from({name:?}), iced::theme::Palette {{ {} }})",
+            fields.join(", ")
+        )
+    }
+}
+
+/// The code creating `color`.
+pub fn color_code(color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    if a == u8::MAX {
+        format!("iced::Color::from_rgb8({r}, {g}, {b})")
+    } else {
+        format!("iced::Color::from_rgba8({r}, {g}, {b}, {:?})", color.a)
+    }
+}
+
+/// Stores colors as `#rrggbb` or `#rrggbbaa` strings.
+pub(crate) mod hex {
+    use iced::Color;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::values::Value;
+
+    pub fn encode(color: Color) -> String {
+        Value::to_string(&color)
+    }
+
+    pub fn serialize<S: Serializer>(
+        color: &Color,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode(*color))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Color, D::Error> {
+        decode(&String::deserialize(deserializer)?)
+    }
+
+    fn decode<E: serde::de::Error>(hex: &str) -> Result<Color, E> {
+        <Color as Value>::from_str(hex).map_err(E::custom)
+    }
+
+    /// Stores optional colors, leaving out missing ones.
+    pub mod option {
+        use iced::Color;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            color: &Option<Color>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match color {
+                Some(color) => super::serialize(color, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Color>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|hex| super::decode(&hex))
+                .transpose()
+        }
+    }
+}
+
+/// Parses a color typed into a palette editor.
+pub fn parse_color(hex: &str) -> Option<Color> {
+    <Color as Value>::from_str(hex).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn palette() -> CustomPalette {
+        CustomPalette {
+            background: Color::from_rgb8(0x1e, 0x1e, 0x2e),
+            text: Color::WHITE,
+            primary: Color::from_rgb8(0x89, 0xb4, 0xfa),
+            success: Color::from_rgb8(0xa6, 0xe3, 0xa1),
+            warning: Color::from_rgb8(0xf9, 0xe2, 0xaf),
+            danger: Color::from_rgba8(0xf3, 0x8b, 0xa8, 0.5),
+        }
+    }
+
+    #[test]
+    fn keeps_the_alpha_of_translucent_colors() {
+        let json = serde_json::to_value(palette()).unwrap();
+
+        assert_eq!(json["background"], "#1e1e2e");
+        assert_eq!(json["danger"], "#f38ba880");
+
+        let palette: CustomPalette = serde_json::from_value(json).unwrap();
+        assert_eq!(palette.danger.into_rgba8(), [0xf3, 0x8b, 0xa8, 0x80])
+    }
+
+    #[test]
+    fn builds_a_custom_theme() {
+        let code = palette().code("Mocha");
+
+        assert!(code.starts_with(
+            "iced::Theme::custom(String::from(\"Mocha\"), iced::theme::Palette { background: iced::Color::from_rgb8(30, 30, 46), "
+        ));
+        assert!(code.ends_with(
+            "danger: iced::Color::from_rgba8(243, 139, 168, 0.5) })"
+        ))
+    }
+
+    #[test]
+    fn can_set_colors() {
+        let mut palette = palette();
+        palette.set(PaletteColor::Warning, Color::BLACK);
+
+        assert_eq!(palette.get(PaletteColor::Warning), Color::BLACK)
+    }
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::RenderedElement;
+
+/// A named screen of the designed app.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct View {
+    pub name: String,
+    pub element_tree: Option<RenderedElement>,
+}
+
+impl View {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            element_tree: None,
+        }
+    }
+
+    /// The name of the view's `Screen` variant in generated code.
+    pub fn variant_name(&self) -> String {
+        let mut variant = String::new();
+
+        for word in words(&self.name) {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                variant.extend(first.to_uppercase());
+                variant.push_str(&chars.as_str().to_lowercase());
+            }
+        }
+
+        identifier(variant, "Screen")
+    }
+
+    /// The name of the function drawing the view in generated code.
+    pub fn function_name(&self) -> String {
+        format!("{}_view", self.snake_case_name("screen"))
+    }
+
+    /// The name of the function drawing the view when it's a component.
+    pub fn component_function_name(&self) -> String {
+        format!("{}_component", self.snake_case_name("element"))
+    }
+
+    fn snake_case_name(&self, fallback: &str) -> String {
+        let name = words(&self.name)
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join("_");
+
+        identifier(name, fallback)
+    }
+}
+
+fn words(name: &str) -> impl Iterator<Item = &str> {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+}
+
+/// Makes sure `name` doesn't start with a digit and isn't empty.
+fn identifier(name: String, fallback: &str) -> String {
+    match name.chars().next() {
+        None => fallback.to_owned(),
+        Some(first) if first.is_ascii_digit() => format!("{fallback}{name}"),
+        Some(_) => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_name_variant() {
+        assert_eq!(View::new("user settings").variant_name(), "UserSettings");
+
+        assert_eq!(View::new("LOGIN-form").variant_name(), "LoginForm")
+    }
+
+    #[test]
+    fn can_name_function() {
+        assert_eq!(
+            View::new("User Settings").function_name(),
+            "user_settings_view"
+        );
+
+        assert_eq!(View::new("").function_name(), "screen_view")
+    }
+
+    #[test]
+    fn can_name_component_function() {
+        assert_eq!(
+            View::new("Stat Card").component_function_name(),
+            "stat_card_component"
+        );
+
+        assert_eq!(View::new("").component_function_name(), "element_component")
+    }
+
+    #[test]
+    fn names_dont_start_with_digit() {
+        assert_eq!(View::new("404 page").variant_name(), "Screen404Page");
+
+        assert_eq!(View::new("404 page").function_name(), "screen404_page_view")
+    }
+}
@@ -1,14 +1,24 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
-use iced::Element;
 use iced::advanced::widget::Id;
 use iced::widget::text::IntoFragment;
 use iced::widget::{self};
+use iced::{Element, Padding};
 use serde::{Deserialize, Serialize};
 
 use crate::Error;
 use crate::options::ApplyOptions;
+use crate::types::asset::{self, AssetMode};
+use crate::types::component::{
+    Codegen, OverrideSlot, override_key, parse_override_key,
+};
+use crate::types::font::ElementFont;
+use crate::types::project::View;
+use crate::types::span::{self, Span};
+use crate::types::style::{ButtonStatus, ElementStyle};
 use crate::types::{ElementName, Message};
+use crate::values::Value;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RenderedElement {
@@ -17,6 +27,14 @@ pub struct RenderedElement {
     child_elements: Option<Vec<RenderedElement>>,
     name: ElementName,
     options: BTreeMap<String, Option<String>>,
+    #[serde(default, skip_serializing_if = "ElementStyle::is_default")]
+    style: ElementStyle,
+    /// The font of texts and button labels.
+    #[serde(default, skip_serializing_if = "ElementFont::is_default")]
+    font: ElementFont,
+    /// What an image or SVG shows, for people who can't see it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
 }
 
 impl RenderedElement {
@@ -26,6 +44,9 @@ impl RenderedElement {
             child_elements: None,
             name,
             options: BTreeMap::new(),
+            style: ElementStyle::default(),
+            font: ElementFont::default(),
+            description: None,
         }
     }
 
@@ -35,6 +56,9 @@ impl RenderedElement {
             child_elements: Some(child_elements),
             name,
             options: BTreeMap::new(),
+            style: ElementStyle::default(),
+            font: ElementFont::default(),
+            description: None,
         }
     }
 
@@ -42,6 +66,45 @@ impl RenderedElement {
         &self.id
     }
 
+    pub fn name(&self) -> &ElementName {
+        &self.name
+    }
+
+    pub fn style(&self) -> &ElementStyle {
+        &self.style
+    }
+
+    pub fn set_style(&mut self, style: ElementStyle) {
+        self.style = style;
+    }
+
+    pub fn font(&self) -> &ElementFont {
+        &self.font
+    }
+
+    pub fn set_font(&mut self, font: ElementFont) {
+        self.font = font;
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+
+    pub fn child_elements(&self) -> &[RenderedElement] {
+        self.child_elements.as_deref().unwrap_or_default()
+    }
+
+    /// Replaces the segments of a rich text, leaving other elements alone.
+    pub fn set_spans(&mut self, spans: Vec<Span>) {
+        if let ElementName::RichText(current) = &mut self.name {
+            *current = spans;
+        }
+    }
+
     pub fn find_by_id(&mut self, id: &Id) -> Option<&mut Self> {
         if self.id() == id {
             Some(self)
@@ -58,6 +121,17 @@ impl RenderedElement {
         }
     }
 
+    pub fn find(&self, id: &Id) -> Option<&Self> {
+        if self.id() == id {
+            Some(self)
+        } else {
+            self.child_elements
+                .iter()
+                .flatten()
+                .find_map(|element| element.find(id))
+        }
+    }
+
     pub fn find_parent(
         &mut self,
         child_element: &RenderedElement,
@@ -93,6 +167,203 @@ impl RenderedElement {
         self.child_elements == Some(vec![])
     }
 
+    /// Checks whether the element or any of its descendants satisfy `f`.
+    pub fn any(&self, f: &impl Fn(&ElementName) -> bool) -> bool {
+        f(&self.name)
+            || self
+                .child_elements
+                .iter()
+                .flatten()
+                .any(|element| element.any(f))
+    }
+
+    /// The name of the component, if the element is an instance of one.
+    pub fn component_name(&self) -> Option<&str> {
+        match &self.name {
+            ElementName::Component(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Returns every component instance in the tree.
+    pub fn instances(&self) -> Vec<&Self> {
+        let mut instances = Vec::new();
+
+        if self.component_name().is_some() {
+            instances.push(self);
+        }
+
+        for element in self.child_elements.iter().flatten() {
+            instances.extend(element.instances());
+        }
+
+        instances
+    }
+
+    /// Makes the family names typed into the element's fonts usable in the
+    /// designer.
+    pub fn commit_fonts(&self) {
+        self.font.commit_family();
+
+        if let ElementName::RichText(spans) = &self.name {
+            for span in spans {
+                span.font.commit_family();
+            }
+        }
+    }
+
+    /// The padding of the element and each of its descendants, by id.
+    pub fn paddings(&self) -> HashMap<Id, Padding> {
+        let padding = self
+            .options
+            .get("padding")
+            .and_then(Option::as_deref)
+            .and_then(|padding| Padding::from_str(padding).ok())
+            .unwrap_or(Padding::ZERO);
+
+        let mut paddings = HashMap::from([(self.id.clone(), padding)]);
+        for element in self.child_elements.iter().flatten() {
+            paddings.extend(element.paddings());
+        }
+
+        paddings
+    }
+
+    /// The overrides of a component instance.
+    pub fn overrides(&self) -> &BTreeMap<String, Option<String>> {
+        &self.options
+    }
+
+    /// Overrides `key` of a component instance, or resets it to the
+    /// component's value with an empty `value`.
+    pub fn set_override(&mut self, key: String, value: String) {
+        if value.is_empty() {
+            let _ = self.options.remove(&key);
+        } else {
+            let _ = self.options.insert(key, Some(value));
+        }
+    }
+
+    fn at_path_mut(&mut self, path: &[usize]) -> Option<&mut Self> {
+        match path.split_first() {
+            None => Some(self),
+            Some((index, rest)) => self
+                .child_elements
+                .as_mut()?
+                .get_mut(*index)?
+                .at_path_mut(rest),
+        }
+    }
+
+    /// Lists what instances of a component with this element tree can
+    /// override: the text of texts and buttons, and the options set.
+    pub fn override_slots(&self) -> Vec<OverrideSlot> {
+        fn collect(
+            element: &RenderedElement,
+            path: &mut Vec<usize>,
+            slots: &mut Vec<OverrideSlot>,
+        ) {
+            if let ElementName::Text(string) | ElementName::Button(string) =
+                &element.name
+            {
+                slots.push(OverrideSlot {
+                    key: override_key(path, "text"),
+                    label: format!("{} text", element.name),
+                    value: string.clone(),
+                });
+            }
+
+            for (option, value) in &element.options {
+                if let Some(value) = value {
+                    slots.push(OverrideSlot {
+                        key: override_key(path, option),
+                        label: format!("{} {option}", element.name),
+                        value: value.clone(),
+                    });
+                }
+            }
+
+            for (index, child) in
+                element.child_elements.iter().flatten().enumerate()
+            {
+                path.push(index);
+                collect(child, path, slots);
+                let _ = path.pop();
+            }
+        }
+
+        let mut slots = Vec::new();
+        collect(self, &mut Vec::new(), &mut slots);
+
+        slots
+    }
+
+    /// Applies the overrides of an instance to a copy of its component's
+    /// element tree.
+    pub fn with_overrides(
+        &self,
+        overrides: &BTreeMap<String, Option<String>>,
+    ) -> Self {
+        let mut element = self.clone();
+
+        for (key, value) in overrides {
+            let (Some((path, option)), Some(value)) =
+                (parse_override_key(key), value)
+            else {
+                continue;
+            };
+            let Some(target) = element.at_path_mut(&path) else {
+                continue;
+            };
+
+            match (&mut target.name, option) {
+                (
+                    ElementName::Text(string) | ElementName::Button(string),
+                    "text",
+                ) => *string = value.clone(),
+                (_, option) => {
+                    if let Some(current) = target.options.get_mut(option) {
+                        *current = Some(value.clone());
+                    }
+                }
+            }
+        }
+
+        element
+    }
+
+    /// Returns the paths of every image and SVG in the tree.
+    pub fn asset_paths(&self) -> Vec<&str> {
+        let mut paths = Vec::new();
+
+        if let ElementName::Image(path) | ElementName::Svg(path) = &self.name {
+            paths.push(path.as_str());
+        }
+
+        for element in self.child_elements.iter().flatten() {
+            paths.extend(element.asset_paths());
+        }
+
+        paths
+    }
+
+    /// Mutable version of [`Self::asset_paths`].
+    pub fn asset_paths_mut(&mut self) -> Vec<&mut String> {
+        let mut paths = Vec::new();
+
+        if let ElementName::Image(path) | ElementName::Svg(path) =
+            &mut self.name
+        {
+            paths.push(path);
+        }
+
+        for element in self.child_elements.iter_mut().flatten() {
+            paths.extend(element.asset_paths_mut());
+        }
+
+        paths
+    }
+
     pub fn remove(&mut self, element: &RenderedElement) {
         let Some(parent) = self.find_parent(element) else {
             return;
@@ -159,13 +430,25 @@ impl RenderedElement {
         }
     }
 
-    fn preset_options(mut self, options: &[&str]) -> Self {
-        for opt in options {
-            let _ = self.options.insert((*opt).to_string(), None);
+    fn preset_options(mut self) -> Self {
+        for opt in self.name.option_names() {
+            let _ = self.options.entry((*opt).to_string()).or_insert(None);
         }
         self
     }
 
+    /// Adds back the options of the tree that aren't stored in the project
+    /// file, e.g. because they were never set.
+    pub fn restore_options(&mut self) {
+        for opt in self.name.option_names() {
+            let _ = self.options.entry((*opt).to_string()).or_insert(None);
+        }
+
+        for element in self.child_elements.iter_mut().flatten() {
+            element.restore_options();
+        }
+    }
+
     pub fn option(mut self, option: String, value: String) -> Self {
         let _ = self
             .options
@@ -174,27 +457,72 @@ impl RenderedElement {
         self
     }
 
-    pub fn codegen(&self) -> (String, String) {
+    pub fn codegen(&self, context: &Codegen<'_>) -> (String, String) {
+        self.codegen_at(context, &mut Vec::new())
+    }
+
+    /// Generates the code of the element at `path` in the tree, for
+    /// looking up component parameters.
+    fn codegen_at(
+        &self,
+        context: &Codegen<'_>,
+        path: &mut Vec<usize>,
+    ) -> (String, String) {
         let mut imports = String::new();
         let mut view = String::new();
         let mut options = String::new();
 
-        for (k, v) in &self.options {
-            if let Some(v) = v {
-                options = format!("{options}.{k}({v})");
+        // The options of instances are overrides, passed to the component
+        if self.component_name().is_none() {
+            for (k, v) in &self.options {
+                if let Some(param) = context.param(&override_key(path, k)) {
+                    options = format!("{options}.{k}({param})");
+                } else if let Some(v) = v {
+                    options = format!("{options}.{k}({v})");
+                }
             }
         }
 
+        if let Some(style) = self.style.code(&self.name) {
+            options = format!("{options}{style}");
+        }
+
         let mut elements = String::new();
 
         if let Some(els) = &self.child_elements {
-            for element in els {
-                let (c_imports, children) = element.codegen();
+            for (index, element) in els.iter().enumerate() {
+                path.push(index);
+                let (c_imports, children) = element.codegen_at(context, path);
+                let _ = path.pop();
+
                 imports = format!("{imports}{c_imports}");
                 elements = format!("{elements}{children},");
             }
         }
 
+        let label = |placeholder: &str, string: &str| {
+            context.param(&override_key(path, "text")).map_or_else(
+                || {
+                    format!(
+                        "\"{}\"",
+                        if string.is_empty() {
+                            placeholder
+                        } else {
+                            string
+                        }
+                    )
+                },
+                str::to_owned,
+            )
+        };
+
+        // iced has no alternative texts, so descriptions stay comments
+        if let Some(description) = &self.description
+            && matches!(self.name, ElementName::Image(_) | ElementName::Svg(_))
+        {
+            view = format!("{view}\n// {}", description.replace('\n', " "));
+        }
+
         match &self.name {
             ElementName::Container => {
                 imports = format!("{imports}container,");
@@ -218,32 +546,70 @@ impl RenderedElement {
             ElementName::Text(string) => {
                 imports = format!("{imports}text,");
                 view = format!(
-                    "{view}\ntext(\"{}\"){options}",
-                    if string.is_empty() {
-                        "New Text"
-                    } else {
-                        string
-                    }
+                    "{view}\ntext({}){}{options}",
+                    label("New Text", string),
+                    self.font.code()
                 );
             }
+            ElementName::RichText(spans) => {
+                imports = format!("{imports}rich_text,span,");
+                view = format!("{view}\n{}{options}", span::code(spans));
+            }
             ElementName::Button(string) => {
+                let font = self.font.code();
+
                 imports = format!("{imports}button,");
-                view = format!(
-                    "{view}\nbutton(\"{}\"){options}",
-                    if string.is_empty() {
-                        "New Button"
-                    } else {
-                        string
-                    }
-                );
+                view = if font.is_empty() {
+                    format!(
+                        "{view}\nbutton({}){options}",
+                        label("New Button", string)
+                    )
+                } else {
+                    imports = format!("{imports}text,");
+                    format!(
+                        "{view}\nbutton(text({}){font}){options}",
+                        label("New Button", string)
+                    )
+                };
             }
-            ElementName::Image(path) => {
+            ElementName::Image(asset_path) => {
                 imports = format!("{imports}image,");
-                view = format!("{view}\nimage(\"{path}\"){options}");
+                view = if context.asset_mode == AssetMode::Embedded
+                    && !asset_path.is_empty()
+                {
+                    format!(
+                        "{view}\nimage(image::Handle::from_bytes(include_bytes!({asset_path:?}).as_slice())){options}"
+                    )
+                } else {
+                    format!("{view}\nimage({asset_path:?}){options}")
+                };
             }
-            ElementName::Svg(path) => {
+            ElementName::Svg(asset_path) => {
                 imports = format!("{imports}svg,");
-                view = format!("{view}\nsvg(\"{path}\"){options}");
+                view = if context.asset_mode == AssetMode::Embedded
+                    && !asset_path.is_empty()
+                {
+                    format!(
+                        "{view}\nsvg(svg::Handle::from_memory(include_bytes!({asset_path:?}).as_slice())){options}"
+                    )
+                } else {
+                    format!("{view}\nsvg({asset_path:?}){options}")
+                };
+            }
+            ElementName::Component(name) => {
+                view = match context
+                    .components
+                    .iter()
+                    .find(|component| component.name == *name)
+                {
+                    Some(component) => {
+                        format!("{view}\n{}", component.call(&self.options))
+                    }
+                    None => {
+                        imports = format!("{imports}text,");
+                        format!("{view}\ntext(\"Missing component {name}\")")
+                    }
+                };
             }
         }
 
@@ -293,48 +659,180 @@ impl std::fmt::Display for RenderedElement {
     }
 }
 
-impl<'a> From<RenderedElement> for Element<'a, Message> {
-    fn from(value: RenderedElement) -> Self {
+/// Information the designer needs to render an element tree.
+#[derive(Debug, Clone, Default)]
+pub struct ViewContext<'a> {
+    pub project_path: Option<PathBuf>,
+    /// The project's components, for drawing their instances.
+    pub components: &'a [View],
+    pub selected: Option<Id>,
+    /// The status buttons are drawn in, since they can't be interacted with.
+    pub button_status: ButtonStatus,
+    /// Whether to draw the tree as the generated code does, outlining
+    /// containers instead of padding them and adding placeholders.
+    pub preview: bool,
+}
+
+impl RenderedElement {
+    pub fn view<'a>(&self, context: &ViewContext<'_>) -> Element<'a, Message> {
+        let content = self.content(context, true);
+
+        let content: Element<'a, Message> =
+            if context.selected.as_ref() == Some(self.id()) {
+                widget::container(content)
+                    .style(|theme: &iced::Theme| widget::container::Style {
+                        border: iced::Border {
+                            color: theme.palette().primary,
+                            width: 2.0,
+                            radius: 4.into(),
+                        },
+                        ..Default::default()
+                    })
+                    .into()
+            } else {
+                content
+            };
+
+        let value = self.clone();
+
+        iced_drop::droppable(content)
+            .id(value.id().clone())
+            .drag_hide(true)
+            .drag_center(true)
+            .on_click(Message::SelectElement(value.id().clone()))
+            .on_drop(move |point, rect| {
+                Message::MoveElement(value.clone(), point, rect)
+            })
+            .into()
+    }
+
+    /// Draws the element, with its children only being draggable if
+    /// `interactive` is set.
+    fn content<'a>(
+        &self,
+        context: &ViewContext<'_>,
+        interactive: bool,
+    ) -> Element<'a, Message> {
         fn text<'a>(string: impl IntoFragment<'a>) -> widget::Text<'a> {
             widget::text(string).style(widget::text::base)
         }
 
-        let copy = value.clone();
+        fn placeholder(theme: &iced::Theme) -> iced::Border {
+            iced::Border {
+                color: theme.palette().text,
+                width: 2.0,
+                radius: 4.into(),
+            }
+        }
+
+        /// Outlines a container on top of it, leaving its layout alone.
+        fn outlined<'a>(content: Element<'a, Message>) -> Element<'a, Message> {
+            widget::stack![
+                content,
+                widget::container(widget::Space::new(
+                    iced::Length::Fill,
+                    iced::Length::Fill
+                ))
+                .style(|theme: &iced::Theme| {
+                    widget::container::Style {
+                        border: iced::Border {
+                            color: theme.palette().text.scale_alpha(0.3),
+                            width: 1.0,
+                            radius: 0.into(),
+                        },
+                        ..Default::default()
+                    }
+                })
+            ]
+            .into()
+        }
+
+        let copy = self.clone();
+        let style = copy.style;
+        let button_status = context.button_status;
+        let with_font = |text: widget::Text<'a>| {
+            let text = text.shaping(copy.font.shaping());
+
+            match copy.font.font() {
+                Some(font) => text.font(font),
+                None => text,
+            }
+        };
         let child_elements = copy.child_elements.unwrap_or_default();
+        let asset = |path: String| {
+            asset::resolve(Path::new(&path), context.project_path.as_deref())
+        };
+        let child = |element: &RenderedElement| {
+            if interactive {
+                element.view(context)
+            } else {
+                element.content(context, false)
+            }
+        };
 
-        let content: Element<'a, Message> = match copy.name {
-            ElementName::Text(s) => if s.is_empty() {
+        match copy.name {
+            ElementName::Text(s) => with_font(if s.is_empty() {
                 text("New Text")
             } else {
                 text(s)
-            }
+            })
+            .style(move |theme: &iced::Theme| style.text(theme))
             .apply_options(copy.options)
             .into(),
-            ElementName::Button(s) => widget::button(if s.is_empty() {
-                text("New Button")
-            } else {
-                text(s)
-            })
+            ElementName::RichText(spans) => widget::rich_text(
+                spans.iter().map(Span::to_iced).collect::<Vec<_>>(),
+            )
             .apply_options(copy.options)
             .into(),
+            ElementName::Button(s) => {
+                widget::button(with_font(if s.is_empty() {
+                    text("New Button")
+                } else {
+                    text(s)
+                }))
+                .style(move |theme: &iced::Theme, _status| {
+                    style.button(theme, button_status)
+                })
+                .apply_options(copy.options)
+                .into()
+            }
             ElementName::Svg(p) => {
-                widget::svg(p).apply_options(copy.options).into()
+                widget::svg(asset(p)).apply_options(copy.options).into()
             }
             ElementName::Image(p) => {
-                widget::image(p).apply_options(copy.options).into()
+                widget::image(asset(p)).apply_options(copy.options).into()
             }
+            ElementName::Container if context.preview => outlined(
+                widget::container(match child_elements.first() {
+                    Some(element) => child(element),
+                    None => text("").into(),
+                })
+                .style(move |theme: &iced::Theme| style.container(theme))
+                .apply_options(copy.options)
+                .into(),
+            ),
+            ElementName::Row if context.preview => outlined(
+                widget::Row::with_children(child_elements.iter().map(child))
+                    .apply_options(copy.options)
+                    .into(),
+            ),
+            ElementName::Column if context.preview => outlined(
+                widget::Column::with_children(child_elements.iter().map(child))
+                    .apply_options(copy.options)
+                    .into(),
+            ),
             ElementName::Container => if child_elements.len() == 1 {
-                widget::container(child_elements[0].clone())
+                widget::container(child(&child_elements[0]))
+                    .style(move |theme: &iced::Theme| style.container(theme))
             } else {
                 widget::container(text("New Container")).style(
-                    |theme: &iced::Theme| widget::container::Style {
-                        border: iced::Border {
-                            color: theme.palette().text,
+                    move |theme: &iced::Theme| {
+                        let mut container = style.container(theme);
+                        if style.border.is_none() {
+                            container.border = placeholder(theme);
+                        }
 
-                            width: 2.0,
-                            radius: 4.into(),
-                        },
-                        ..Default::default()
+                        container
                     },
                 )
             }
@@ -343,12 +841,10 @@ impl<'a> From<RenderedElement> for Element<'a, Message> {
             .into(),
             ElementName::Row => {
                 if !child_elements.is_empty() {
-                    widget::Row::with_children(
-                        child_elements.into_iter().map(Into::into),
-                    )
-                    .padding(20)
-                    .apply_options(copy.options)
-                    .into()
+                    widget::Row::with_children(child_elements.iter().map(child))
+                        .padding(20)
+                        .apply_options(copy.options)
+                        .into()
                 } else {
                     widget::container(
                         widget::row![text("New Row")]
@@ -356,12 +852,7 @@ impl<'a> From<RenderedElement> for Element<'a, Message> {
                             .apply_options(copy.options),
                     )
                     .style(|theme: &iced::Theme| widget::container::Style {
-                        border: iced::Border {
-                            color: theme.palette().text,
-
-                            width: 2.0,
-                            radius: 4.into(),
-                        },
+                        border: placeholder(theme),
                         ..Default::default()
                     })
                     .into()
@@ -370,7 +861,7 @@ impl<'a> From<RenderedElement> for Element<'a, Message> {
             ElementName::Column => {
                 if !child_elements.is_empty() {
                     widget::Column::with_children(
-                        child_elements.into_iter().map(Into::into),
+                        child_elements.iter().map(child),
                     )
                     .padding(20)
                     .apply_options(copy.options)
@@ -382,27 +873,24 @@ impl<'a> From<RenderedElement> for Element<'a, Message> {
                             .apply_options(copy.options),
                     )
                     .style(|theme: &iced::Theme| widget::container::Style {
-                        border: iced::Border {
-                            color: theme.palette().text,
-
-                            width: 2.0,
-                            radius: 4.into(),
-                        },
+                        border: placeholder(theme),
                         ..Default::default()
                     })
                     .into()
                 }
             }
-        };
-
-        iced_drop::droppable(content)
-            .id(value.id().clone())
-            .drag_hide(true)
-            .drag_center(true)
-            .on_drop(move |point, rect| {
-                Message::MoveElement(value.clone(), point, rect)
-            })
-            .into()
+            ElementName::Component(name) => match context
+                .components
+                .iter()
+                .find(|component| component.name == name)
+                .and_then(|component| component.element_tree.as_ref())
+            {
+                Some(element_tree) => element_tree
+                    .with_overrides(&copy.options)
+                    .content(context, false),
+                None => text(format!("Missing component {name}")).into(),
+            },
+        }
     }
 }
 
@@ -471,40 +959,23 @@ impl<'a: 'b, 'b> Action<'a> {
 }
 
 pub fn text(text: &str) -> RenderedElement {
-    RenderedElement::new(ElementName::Text(text.to_owned())).preset_options(&[
-        "size",
-        "line_height",
-        "width",
-        "height",
-        "align_x",
-        "align_y",
-    ])
+    RenderedElement::new(ElementName::Text(text.to_owned())).preset_options()
+}
+
+pub fn rich_text(spans: Vec<Span>) -> RenderedElement {
+    RenderedElement::new(ElementName::RichText(spans)).preset_options()
 }
 
 pub fn button(text: &str) -> RenderedElement {
-    RenderedElement::new(ElementName::Button(text.to_owned()))
-        .preset_options(&["width", "height", "padding", "clip"])
+    RenderedElement::new(ElementName::Button(text.to_owned())).preset_options()
 }
 
 pub fn svg(path: &str) -> RenderedElement {
-    RenderedElement::new(ElementName::Svg(path.to_owned())).preset_options(&[
-        "width",
-        "height",
-        "content_fit",
-        "rotation",
-        "opacity",
-    ])
+    RenderedElement::new(ElementName::Svg(path.to_owned())).preset_options()
 }
 
 pub fn image(path: &str) -> RenderedElement {
-    RenderedElement::new(ElementName::Image(path.to_owned())).preset_options(&[
-        "width",
-        "height",
-        "content_fit",
-        "rotation",
-        "opacity",
-        "scale",
-    ])
+    RenderedElement::new(ElementName::Image(path.to_owned())).preset_options()
 }
 
 pub fn container(content: Option<RenderedElement>) -> RenderedElement {
@@ -512,41 +983,17 @@ pub fn container(content: Option<RenderedElement>) -> RenderedElement {
         Some(el) => RenderedElement::with(ElementName::Container, vec![el]),
         None => RenderedElement::with(ElementName::Container, vec![]),
     }
-    .preset_options(&[
-        "padding",
-        "width",
-        "height",
-        "max_width",
-        "max_height",
-        "center_x",
-        "center_y",
-        "center",
-        "align_left",
-        "align_right",
-        "align_top",
-        "align_bottom",
-        "align_x",
-        "align_y",
-        "clip",
-    ])
+    .preset_options()
 }
 
 pub fn row(child_elements: Vec<RenderedElement>) -> RenderedElement {
-    RenderedElement::with(ElementName::Row, child_elements).preset_options(&[
-        "spacing", "padding", "width", "height", "align_y", "clip",
-    ])
+    RenderedElement::with(ElementName::Row, child_elements).preset_options()
 }
 
 pub fn column(child_elements: Vec<RenderedElement>) -> RenderedElement {
-    RenderedElement::with(ElementName::Column, child_elements).preset_options(
-        &[
-            "spacing",
-            "padding",
-            "width",
-            "height",
-            "max_width",
-            "align_x",
-            "clip",
-        ],
-    )
+    RenderedElement::with(ElementName::Column, child_elements).preset_options()
+}
+
+pub fn component(name: &str) -> RenderedElement {
+    RenderedElement::new(ElementName::Component(name.to_owned()))
 }
@@ -0,0 +1,199 @@
+//! The segments of rich texts.
+use iced::Color;
+use iced::widget::text;
+use serde::{Deserialize, Serialize};
+
+use crate::types::font::ElementFont;
+use crate::types::project::{color_code, hex};
+
+/// A run of a rich text with its own color, size, font and link.
+///
+/// Colors are fixed rather than taken from the theme, as spans are built
+/// outside of any style closure.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Span {
+    pub text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "hex::option"
+    )]
+    pub color: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<f32>,
+    #[serde(default, skip_serializing_if = "ElementFont::is_default")]
+    pub font: ElementFont,
+    /// The URL the span links to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+}
+
+impl Span {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_owned(),
+            ..Self::default()
+        }
+    }
+
+    /// The span drawn in the designer, where links can't be followed.
+    pub fn to_iced(&self) -> text::Span<'static, String> {
+        let mut span = text::Span::new(self.text.clone())
+            .color_maybe(self.color)
+            .font_maybe(self.font.font())
+            .link_maybe(self.link.clone())
+            .underline(self.link.is_some());
+
+        if let Some(size) = self.size {
+            span = span.size(size);
+        }
+
+        span
+    }
+
+    pub fn code(&self) -> String {
+        let mut code = format!("span({:?})", self.text);
+
+        if let Some(color) = self.color {
+            code = format!("{code}.color({})", color_code(color));
+        }
+        if let Some(size) = self.size {
+            code = format!("{code}.size({size:?})");
+        }
+        if let Some(font) = self.font.font_code() {
+            code = format!("{code}.font({font})");
+        }
+        if let Some(link) = &self.link {
+            code = format!("{code}.link({link:?}).underline(true)");
+        }
+
+        code
+    }
+}
+
+/// Whether any of `spans` links somewhere, which the generated app needs a
+/// message for.
+pub fn has_links(spans: &[Span]) -> bool {
+    spans.iter().any(|span| span.link.is_some())
+}
+
+/// The segment whose text is all that differs between `old` and `new`, as
+/// while it's typed.
+pub fn retyped(old: &[Span], new: &[Span]) -> Option<usize> {
+    if old.len() != new.len() {
+        return None;
+    }
+
+    let mut changed = old
+        .iter()
+        .zip(new)
+        .enumerate()
+        .filter(|(_, (old, new))| old != new);
+    let (index, (old, new)) = changed.next()?;
+
+    (changed.next().is_none()
+        && Span {
+            text: new.text.clone(),
+            ..old.clone()
+        } == *new)
+        .then_some(index)
+}
+
+/// The `rich_text!` building the spans, with links sent as
+/// `Message::LinkClicked`.
+pub fn code(spans: &[Span]) -> String {
+    let code: Vec<String> = spans.iter().map(Span::code).collect();
+
+    format!(
+        "rich_text![{}].on_link_click({})",
+        code.join(", "),
+        if has_links(spans) {
+            "Message::LinkClicked"
+        } else {
+            "iced::never"
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaves_out_what_isnt_set() {
+        let span = Span {
+            color: Some(Color::from_rgb8(0xff, 0, 0)),
+            ..Span::new("iced")
+        };
+        let json = serde_json::to_value(&span).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({ "text": "iced", "color": "#ff0000" })
+        );
+        assert_eq!(
+            serde_json::from_value::<Span>(
+                serde_json::json!({ "text": "iced" })
+            )
+            .unwrap(),
+            Span::new("iced")
+        );
+        assert!(
+            serde_json::from_value::<Span>(
+                serde_json::json!({ "text": "iced", "color": "red" })
+            )
+            .is_err()
+        )
+    }
+
+    #[test]
+    fn links_are_sent_as_messages() {
+        let spans = [
+            Span {
+                size: Some(20.0),
+                ..Span::new("Hello, ")
+            },
+            Span {
+                link: Some(String::from("https://iced.rs")),
+                ..Span::new("iced")
+            },
+        ];
+
+        assert_eq!(
+            code(&spans),
+            "rich_text![span(\"Hello, \").size(20.0), span(\"iced\").link(\"https://iced.rs\").underline(true)].on_link_click(Message::LinkClicked)"
+        );
+        assert!(has_links(&spans))
+    }
+
+    #[test]
+    fn spans_without_links_never_click() {
+        assert!(
+            code(&[Span::new("Hi")]).ends_with(".on_link_click(iced::never)")
+        )
+    }
+
+    #[test]
+    fn finds_the_retyped_segment() {
+        let old = [Span::new("Hello"), Span::new("iced")];
+
+        assert_eq!(
+            retyped(&old, &[Span::new("Hello"), Span::new("ice")]),
+            Some(1)
+        );
+        assert_eq!(
+            retyped(
+                &old,
+                &[
+                    Span {
+                        size: Some(20.0),
+                        ..Span::new("Hello")
+                    },
+                    Span::new("iced")
+                ]
+            ),
+            None
+        );
+        assert_eq!(retyped(&old, &old[..1]), None)
+    }
+}
@@ -0,0 +1,740 @@
+//! Colors, borders and shadows of elements, on top of their layout options.
+use iced::theme::Palette;
+use iced::widget::{button, container, text};
+use iced::{Color, Degrees, Vector, gradient};
+use serde::{Deserialize, Serialize};
+
+use crate::types::ElementName;
+use crate::types::project::{
+    CustomPalette, PaletteColor, color_code, hex, parse_color,
+};
+
+/// A color of an element style, either taken from the theme of the app
+/// or fixed.
+///
+/// Stored as the lowercase name of the palette color, or as `#rrggbb`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum ColorRef {
+    Palette(PaletteColor),
+    Literal(Color),
+}
+
+impl ColorRef {
+    pub fn resolve(self, palette: Palette) -> Color {
+        match self {
+            Self::Palette(color) => CustomPalette::from(palette).get(color),
+            Self::Literal(color) => color,
+        }
+    }
+
+    /// The code of the color, inside a closure with a `theme` argument.
+    pub fn code(self) -> String {
+        match self {
+            Self::Palette(color) => {
+                format!("theme.palette().{}", color.to_string().to_lowercase())
+            }
+            Self::Literal(color) => color_code(color),
+        }
+    }
+}
+
+impl From<ColorRef> for String {
+    fn from(color: ColorRef) -> Self {
+        match color {
+            ColorRef::Palette(color) => color.to_string().to_lowercase(),
+            ColorRef::Literal(color) => hex::encode(color),
+        }
+    }
+}
+
+impl TryFrom<String> for ColorRef {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if let Some(color) = PaletteColor::ALL
+            .iter()
+            .find(|color| color.to_string().eq_ignore_ascii_case(&value))
+        {
+            return Ok(Self::Palette(*color));
+        }
+
+        parse_color(&value)
+            .map(Self::Literal)
+            .ok_or_else(|| format!("invalid color `{value}`"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Background {
+    Color(ColorRef),
+    /// A linear gradient, with its angle in degrees.
+    Gradient {
+        angle: f32,
+        start: ColorRef,
+        end: ColorRef,
+    },
+}
+
+impl Default for Background {
+    fn default() -> Self {
+        Self::Color(ColorRef::Palette(PaletteColor::Primary))
+    }
+}
+
+impl Background {
+    /// A gradient starting from the current color.
+    pub fn gradient(self) -> Self {
+        match self {
+            Self::Color(start) => Self::Gradient {
+                angle: 90.0,
+                start,
+                end: ColorRef::Palette(PaletteColor::Background),
+            },
+            gradient @ Self::Gradient { .. } => gradient,
+        }
+    }
+
+    /// A plain background with the start color of the gradient.
+    pub fn color(self) -> Self {
+        match self {
+            Self::Gradient { start, .. } => Self::Color(start),
+            color @ Self::Color(_) => color,
+        }
+    }
+
+    fn resolve(self, palette: Palette) -> iced::Background {
+        match self {
+            Self::Color(color) => color.resolve(palette).into(),
+            Self::Gradient { angle, start, end } => iced::Background::Gradient(
+                gradient::Linear::new(Degrees(angle))
+                    .add_stop(0.0, start.resolve(palette))
+                    .add_stop(1.0, end.resolve(palette))
+                    .into(),
+            ),
+        }
+    }
+
+    fn code(self) -> String {
+        match self {
+            Self::Color(color) => format!("{}.into()", color.code()),
+            Self::Gradient { angle, start, end } => format!(
+                "iced::Background::Gradient(iced::gradient::Linear::new(iced::Degrees({angle:?})).add_stop(0.0, {}).add_stop(1.0, {}).into())",
+                start.code(),
+                end.code()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Border {
+    pub width: f32,
+    pub radius: f32,
+    pub color: ColorRef,
+}
+
+impl Default for Border {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            radius: 4.0,
+            color: ColorRef::Palette(PaletteColor::Text),
+        }
+    }
+}
+
+impl Border {
+    fn resolve(self, palette: Palette) -> iced::Border {
+        iced::Border {
+            color: self.color.resolve(palette),
+            width: self.width,
+            radius: self.radius.into(),
+        }
+    }
+
+    fn code(self) -> String {
+        format!(
+            "iced::Border {{ color: {}, width: {:?}, radius: {:?}.into() }}",
+            self.color.code(),
+            self.width,
+            self.radius
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Shadow {
+    pub color: ColorRef,
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub blur: f32,
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self {
+            color: ColorRef::Literal(Color::from_rgba(0.0, 0.0, 0.0, 0.5)),
+            offset_x: 0.0,
+            offset_y: 2.0,
+            blur: 8.0,
+        }
+    }
+}
+
+impl Shadow {
+    fn resolve(self, palette: Palette) -> iced::Shadow {
+        iced::Shadow {
+            color: self.color.resolve(palette),
+            offset: Vector::new(self.offset_x, self.offset_y),
+            blur_radius: self.blur,
+        }
+    }
+
+    fn code(self) -> String {
+        format!(
+            "iced::Shadow {{ color: {}, offset: iced::Vector::new({:?}, {:?}), blur_radius: {:?} }}",
+            self.color.code(),
+            self.offset_x,
+            self.offset_y,
+            self.blur
+        )
+    }
+}
+
+/// Colors, border and shadow, with unset parts left to the theme.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Appearance {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<ColorRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<Background>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub border: Option<Border>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<Shadow>,
+}
+
+impl Appearance {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Applies the set parts on top of the `base` style of a button.
+    fn apply(&self, palette: Palette, base: button::Style) -> button::Style {
+        button::Style {
+            text_color: self
+                .text_color
+                .map_or(base.text_color, |color| color.resolve(palette)),
+            background: self
+                .background
+                .map(|background| background.resolve(palette))
+                .or(base.background),
+            border: self
+                .border
+                .map_or(base.border, |border| border.resolve(palette)),
+            shadow: self
+                .shadow
+                .map_or(base.shadow, |shadow| shadow.resolve(palette)),
+            ..base
+        }
+    }
+
+    /// The fields of a style struct setting the set parts, where the text
+    /// color of buttons isn't optional.
+    fn fields(&self, is_button: bool) -> Vec<String> {
+        let mut fields = Vec::new();
+
+        if let Some(color) = self.text_color {
+            fields.push(if is_button {
+                format!("text_color: {}", color.code())
+            } else {
+                format!("text_color: Some({})", color.code())
+            });
+        }
+        if let Some(background) = self.background {
+            fields.push(format!("background: Some({})", background.code()));
+        }
+        if let Some(border) = self.border {
+            fields.push(format!("border: {}", border.code()));
+        }
+        if let Some(shadow) = self.shadow {
+            fields.push(format!("shadow: {}", shadow.code()));
+        }
+
+        fields
+    }
+}
+
+/// The built-in styles of `iced::widget::button`.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum ButtonVariant {
+    #[default]
+    Primary,
+    Secondary,
+    Success,
+    Danger,
+    Text,
+}
+
+impl ButtonVariant {
+    pub const ALL: &'static [Self] = &[
+        Self::Primary,
+        Self::Secondary,
+        Self::Success,
+        Self::Danger,
+        Self::Text,
+    ];
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn style(
+        self,
+        theme: &iced::Theme,
+        status: button::Status,
+    ) -> button::Style {
+        match self {
+            Self::Primary => button::primary(theme, status),
+            Self::Secondary => button::secondary(theme, status),
+            Self::Success => button::success(theme, status),
+            Self::Danger => button::danger(theme, status),
+            Self::Text => button::text(theme, status),
+        }
+    }
+
+    fn code(self) -> String {
+        format!("iced::widget::button::{}", self.to_string().to_lowercase())
+    }
+}
+
+impl std::fmt::Display for ButtonVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Primary => "Primary",
+            Self::Secondary => "Secondary",
+            Self::Success => "Success",
+            Self::Danger => "Danger",
+            Self::Text => "Text",
+        })
+    }
+}
+
+/// The states a button is drawn in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ButtonStatus {
+    #[default]
+    Active,
+    Hovered,
+    Pressed,
+    Disabled,
+}
+
+impl ButtonStatus {
+    pub const ALL: &'static [Self] =
+        &[Self::Active, Self::Hovered, Self::Pressed, Self::Disabled];
+}
+
+impl From<button::Status> for ButtonStatus {
+    fn from(status: button::Status) -> Self {
+        match status {
+            button::Status::Active => Self::Active,
+            button::Status::Hovered => Self::Hovered,
+            button::Status::Pressed => Self::Pressed,
+            button::Status::Disabled => Self::Disabled,
+        }
+    }
+}
+
+impl From<ButtonStatus> for button::Status {
+    fn from(status: ButtonStatus) -> Self {
+        match status {
+            ButtonStatus::Active => Self::Active,
+            ButtonStatus::Hovered => Self::Hovered,
+            ButtonStatus::Pressed => Self::Pressed,
+            ButtonStatus::Disabled => Self::Disabled,
+        }
+    }
+}
+
+impl std::fmt::Display for ButtonStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Active => "Active",
+            Self::Hovered => "Hovered",
+            Self::Pressed => "Pressed",
+            Self::Disabled => "Disabled",
+        })
+    }
+}
+
+/// Which parts of an [`ElementStyle`] an element can use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Styling {
+    None,
+    /// Only the text color.
+    Text,
+    /// Everything but the button variant and statuses.
+    Box,
+    Button,
+}
+
+impl Styling {
+    pub fn of(name: &ElementName) -> Self {
+        match name {
+            ElementName::Text(_) => Self::Text,
+            ElementName::Container => Self::Box,
+            ElementName::Button(_) => Self::Button,
+            _ => Self::None,
+        }
+    }
+}
+
+/// The style of an element, with buttons also having a built-in variant
+/// and parts overridden per status.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ElementStyle {
+    #[serde(flatten)]
+    pub base: Appearance,
+    #[serde(default, skip_serializing_if = "ButtonVariant::is_default")]
+    pub variant: ButtonVariant,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hovered: Option<Appearance>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pressed: Option<Appearance>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<Appearance>,
+}
+
+impl ElementStyle {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The parts set for buttons in `status`, with the base ones being
+    /// those of [`ButtonStatus::Active`].
+    pub fn layer(&self, status: ButtonStatus) -> Appearance {
+        match status {
+            ButtonStatus::Active => Some(self.base),
+            ButtonStatus::Hovered => self.hovered,
+            ButtonStatus::Pressed => self.pressed,
+            ButtonStatus::Disabled => self.disabled,
+        }
+        .unwrap_or_default()
+    }
+
+    pub fn with_layer(
+        mut self,
+        status: ButtonStatus,
+        layer: Appearance,
+    ) -> Self {
+        let overrides = (!layer.is_empty()).then_some(layer);
+
+        match status {
+            ButtonStatus::Active => self.base = layer,
+            ButtonStatus::Hovered => self.hovered = overrides,
+            ButtonStatus::Pressed => self.pressed = overrides,
+            ButtonStatus::Disabled => self.disabled = overrides,
+        }
+
+        self
+    }
+
+    pub fn text(&self, theme: &iced::Theme) -> text::Style {
+        text::Style {
+            color: self
+                .base
+                .text_color
+                .map(|color| color.resolve(theme.palette())),
+        }
+    }
+
+    pub fn container(&self, theme: &iced::Theme) -> container::Style {
+        let palette = theme.palette();
+        let base = &self.base;
+
+        container::Style {
+            text_color: base.text_color.map(|color| color.resolve(palette)),
+            background: base
+                .background
+                .map(|background| background.resolve(palette)),
+            border: base
+                .border
+                .map(|border| border.resolve(palette))
+                .unwrap_or_default(),
+            shadow: base
+                .shadow
+                .map(|shadow| shadow.resolve(palette))
+                .unwrap_or_default(),
+            ..container::Style::default()
+        }
+    }
+
+    /// The style of a button in `status`: its variant, then the base parts
+    /// and the parts of the status on top.
+    pub fn button(
+        &self,
+        theme: &iced::Theme,
+        status: ButtonStatus,
+    ) -> button::Style {
+        let palette = theme.palette();
+        let style = self
+            .base
+            .apply(palette, self.variant.style(theme, status.into()));
+
+        match status {
+            ButtonStatus::Active => style,
+            status => self.layer(status).apply(palette, style),
+        }
+    }
+
+    /// The `.style(..)` call giving an element of kind `name` this style,
+    /// if it changes anything.
+    pub fn code(&self, name: &ElementName) -> Option<String> {
+        if self.is_default() {
+            return None;
+        }
+
+        match Styling::of(name) {
+            Styling::None => None,
+            Styling::Text => {
+                let color = self.base.text_color?;
+
+                Some(closure(
+                    "",
+                    format!(
+                        "iced::widget::text::Style {{ color: Some({}) }}",
+                        color.code()
+                    ),
+                ))
+            }
+            Styling::Box => {
+                let fields = self.base.fields(false);
+
+                (!fields.is_empty()).then(|| {
+                    closure(
+                        "",
+                        format!(
+                            "iced::widget::container::Style {{ {}, ..Default::default() }}",
+                            fields.join(", ")
+                        ),
+                    )
+                })
+            }
+            Styling::Button => self.button_code(),
+        }
+    }
+
+    fn button_code(&self) -> Option<String> {
+        let variant = self.variant.code();
+        let fields = self.base.fields(true);
+        let statuses: Vec<String> = ButtonStatus::ALL[1..]
+            .iter()
+            .filter_map(|status| {
+                let fields = self.layer(*status).fields(true);
+
+                (!fields.is_empty()).then(|| {
+                    format!(
+                        "iced::widget::button::Status::{status:?} => iced::widget::button::Style {{ {}, ..style }},",
+                        fields.join(", ")
+                    )
+                })
+            })
+            .collect();
+
+        if fields.is_empty() && statuses.is_empty() {
+            return (!self.variant.is_default())
+                .then(|| format!(".style({variant})"));
+        }
+
+        let style = if fields.is_empty() {
+            format!("{variant}(theme, status)")
+        } else {
+            format!(
+                "iced::widget::button::Style {{ {}, ..{variant}(theme, status) }}",
+                fields.join(", ")
+            )
+        };
+
+        Some(closure(
+            ", status",
+            if statuses.is_empty() {
+                style
+            } else {
+                format!(
+                    "{{ let style = {style}; match status {{ {} _ => style }} }}",
+                    statuses.join(" ")
+                )
+            },
+        ))
+    }
+}
+
+/// Wraps `body` in a style closure, with `rest` the arguments after the
+/// theme.
+fn closure(rest: &str, body: String) -> String {
+    let theme = if body.contains("theme") { "theme" } else { "_" };
+
+    format!(".style(|{theme}: &iced::Theme{rest}| {body})")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_are_stored_as_strings() {
+        let style = ElementStyle {
+            base: Appearance {
+                text_color: Some(ColorRef::Palette(PaletteColor::Primary)),
+                border: Some(Border {
+                    color: ColorRef::Literal(Color::from_rgb8(0xff, 0, 0)),
+                    ..Border::default()
+                }),
+                ..Appearance::default()
+            },
+            ..ElementStyle::default()
+        };
+        let json = serde_json::to_value(style).unwrap();
+
+        assert_eq!(json["text_color"], "primary");
+        assert_eq!(json["border"]["color"], "#ff0000");
+        assert!(json.get("background").is_none());
+
+        let parsed: ElementStyle = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, style)
+    }
+
+    #[test]
+    fn invalid_colors_are_rejected() {
+        assert!(ColorRef::try_from(String::from("purple")).is_err())
+    }
+
+    #[test]
+    fn palette_colors_follow_the_theme() {
+        let color = ColorRef::Palette(PaletteColor::Danger);
+
+        assert_eq!(
+            color.resolve(iced::Theme::Nord.palette()),
+            iced::Theme::Nord.palette().danger
+        )
+    }
+
+    #[test]
+    fn default_style_has_no_code() {
+        assert_eq!(ElementStyle::default().code(&ElementName::Container), None)
+    }
+
+    #[test]
+    fn can_generate_text_style() {
+        let style = ElementStyle {
+            base: Appearance {
+                text_color: Some(ColorRef::Literal(Color::WHITE)),
+                border: Some(Border::default()),
+                ..Appearance::default()
+            },
+            ..ElementStyle::default()
+        };
+
+        assert_eq!(
+            style.code(&ElementName::Text(String::new())).as_deref(),
+            Some(
+                ".style(|_: &iced::Theme| iced::widget::text::Style { color: Some(iced::Color::from_rgb8(255, 255, 255)) })"
+            )
+        );
+        assert_eq!(style.code(&ElementName::Row), None)
+    }
+
+    #[test]
+    fn can_generate_button_style() {
+        let style = ElementStyle {
+            base: Appearance {
+                background: Some(Background::default().gradient()),
+                ..Appearance::default()
+            },
+            ..ElementStyle::default()
+        };
+
+        assert_eq!(
+            style.code(&ElementName::Button(String::new())).as_deref(),
+            Some(
+                ".style(|theme: &iced::Theme, status| iced::widget::button::Style { background: Some(iced::Background::Gradient(iced::gradient::Linear::new(iced::Degrees(90.0)).add_stop(0.0, theme.palette().primary).add_stop(1.0, theme.palette().background).into())), ..iced::widget::button::primary(theme, status) })"
+            )
+        )
+    }
+
+    #[test]
+    fn can_generate_container_style() {
+        let style = ElementStyle {
+            base: Appearance {
+                text_color: Some(ColorRef::Palette(PaletteColor::Text)),
+                shadow: Some(Shadow::default()),
+                ..Appearance::default()
+            },
+            ..ElementStyle::default()
+        };
+
+        assert_eq!(
+            style.code(&ElementName::Container).as_deref(),
+            Some(
+                ".style(|theme: &iced::Theme| iced::widget::container::Style { text_color: Some(theme.palette().text), shadow: iced::Shadow { color: iced::Color::from_rgba8(0, 0, 0, 0.5), offset: iced::Vector::new(0.0, 2.0), blur_radius: 8.0 }, ..Default::default() })"
+            )
+        )
+    }
+
+    #[test]
+    fn can_generate_button_variant() {
+        let style = ElementStyle {
+            variant: ButtonVariant::Danger,
+            ..ElementStyle::default()
+        };
+
+        assert_eq!(
+            style.code(&ElementName::Button(String::new())).as_deref(),
+            Some(".style(iced::widget::button::danger)")
+        )
+    }
+
+    #[test]
+    fn can_generate_status_overrides() {
+        let style = ElementStyle {
+            variant: ButtonVariant::Secondary,
+            hovered: Some(Appearance {
+                text_color: Some(ColorRef::Palette(PaletteColor::Primary)),
+                ..Appearance::default()
+            }),
+            ..ElementStyle::default()
+        };
+
+        assert_eq!(
+            style.code(&ElementName::Button(String::new())).as_deref(),
+            Some(
+                ".style(|theme: &iced::Theme, status| { let style = iced::widget::button::secondary(theme, status); match status { iced::widget::button::Status::Hovered => iced::widget::button::Style { text_color: theme.palette().primary, ..style }, _ => style } })"
+            )
+        )
+    }
+
+    #[test]
+    fn empty_layers_are_dropped() {
+        let style = ElementStyle::default()
+            .with_layer(ButtonStatus::Pressed, Appearance::default());
+
+        assert_eq!(style.pressed, None);
+        assert!(style.is_default())
+    }
+
+    #[test]
+    fn base_parts_are_stored_flat() {
+        let json = serde_json::json!({ "text_color": "danger", "hovered": {} });
+        let style: ElementStyle = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            style.base.text_color,
+            Some(ColorRef::Palette(PaletteColor::Danger))
+        );
+        assert_eq!(style.hovered, Some(Appearance::default()))
+    }
+}
@@ -0,0 +1,235 @@
+//! The size and zoom of the window the designer previews.
+use iced::Size;
+
+/// The zoom levels stepped through by zooming in and out, in percent.
+const ZOOM_LEVELS: &[u16] = &[25, 50, 75, 100, 125, 150, 200];
+
+/// The smallest scale fitting goes down to, so that a collapsed designer
+/// doesn't scale the window to nothing.
+const MIN_SCALE: f32 = 0.05;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Preset {
+    /// The window size set in the project, or the desktop size without one.
+    #[default]
+    Project,
+    Desktop,
+    SmallWindow,
+    MobilePortrait,
+    Custom,
+}
+
+impl Preset {
+    pub const ALL: &'static [Self] = &[
+        Self::Project,
+        Self::Desktop,
+        Self::SmallWindow,
+        Self::MobilePortrait,
+        Self::Custom,
+    ];
+
+    fn size(self) -> Option<Size> {
+        match self {
+            Self::Desktop => Some(Size::new(1280.0, 720.0)),
+            Self::SmallWindow => Some(Size::new(800.0, 600.0)),
+            Self::MobilePortrait => Some(Size::new(390.0, 844.0)),
+            Self::Project | Self::Custom => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Preset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Project => "Project window",
+            Self::Desktop => "Desktop",
+            Self::SmallWindow => "Small window",
+            Self::MobilePortrait => "Mobile portrait",
+            Self::Custom => "Custom",
+        };
+
+        match self.size() {
+            Some(size) => write!(f, "{name} ({}×{})", size.width, size.height),
+            None => f.write_str(name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zoom {
+    /// Scales the window to fit the designer.
+    Fit,
+    Percent(u16),
+}
+
+/// The window the view is previewed in.
+///
+/// The view is laid out at the size of the window and drawn scaled, so it
+/// looks the same at any zoom, only bigger or smaller.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Viewport {
+    pub preset: Preset,
+    /// The custom size as typed, which falls back to the desktop size
+    /// while it isn't valid.
+    pub width: String,
+    pub height: String,
+    pub zoom: Zoom,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            preset: Preset::default(),
+            width: String::from("1024"),
+            height: String::from("768"),
+            zoom: Zoom::Fit,
+        }
+    }
+}
+
+impl Viewport {
+    /// The size of the previewed window, with `window` being the size set
+    /// in the project.
+    pub fn size(&self, window: Option<Size>) -> Size {
+        let desktop = Preset::Desktop.size().unwrap_or(Size::ZERO);
+
+        match self.preset {
+            Preset::Project => window.unwrap_or(desktop),
+            Preset::Custom => {
+                let dimension = |value: &str| {
+                    value
+                        .trim()
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|value| *value >= 1.0 && value.is_finite())
+                };
+
+                match (dimension(&self.width), dimension(&self.height)) {
+                    (Some(width), Some(height)) => Size::new(width, height),
+                    _ => desktop,
+                }
+            }
+            preset => preset.size().unwrap_or(desktop),
+        }
+    }
+
+    /// The scale the window is drawn at in a designer of size `available`.
+    pub fn scale(&self, window: Option<Size>, available: Size) -> f32 {
+        match self.zoom {
+            Zoom::Fit => {
+                let size = self.size(window);
+
+                (available.width / size.width)
+                    .min(available.height / size.height)
+                    .max(MIN_SCALE)
+            }
+            Zoom::Percent(percent) => f32::from(percent) / 100.0,
+        }
+    }
+
+    /// The next zoom level, going on from 100% when fitting.
+    pub fn zoom_in(&self) -> Self {
+        let current = self.percent().unwrap_or(100);
+        let percent = ZOOM_LEVELS
+            .iter()
+            .copied()
+            .find(|level| *level > current)
+            .unwrap_or(current);
+
+        Self {
+            zoom: Zoom::Percent(percent),
+            ..self.clone()
+        }
+    }
+
+    /// The previous zoom level, going on from 100% when fitting.
+    pub fn zoom_out(&self) -> Self {
+        let current = self.percent().unwrap_or(100);
+        let percent = ZOOM_LEVELS
+            .iter()
+            .copied()
+            .rev()
+            .find(|level| *level < current)
+            .unwrap_or(current);
+
+        Self {
+            zoom: Zoom::Percent(percent),
+            ..self.clone()
+        }
+    }
+
+    pub fn percent(&self) -> Option<u16> {
+        match self.zoom {
+            Zoom::Fit => None,
+            Zoom::Percent(percent) => Some(percent),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_custom_size_falls_back_to_desktop() {
+        let viewport = Viewport {
+            preset: Preset::Custom,
+            width: String::from("wide"),
+            ..Viewport::default()
+        };
+
+        assert_eq!(viewport.size(None), Size::new(1280.0, 720.0));
+
+        let viewport = Viewport {
+            width: String::from(" 640 "),
+            height: String::from("480"),
+            ..viewport
+        };
+
+        assert_eq!(viewport.size(None), Size::new(640.0, 480.0))
+    }
+
+    #[test]
+    fn uses_the_project_window_size() {
+        let viewport = Viewport::default();
+        let window = Size::new(800.0, 600.0);
+
+        assert_eq!(viewport.size(Some(window)), window);
+        assert_eq!(viewport.size(None), Size::new(1280.0, 720.0))
+    }
+
+    #[test]
+    fn fit_never_scales_to_nothing() {
+        let viewport = Viewport::default();
+
+        assert_eq!(viewport.scale(None, Size::ZERO), MIN_SCALE);
+        assert_eq!(viewport.scale(None, Size::new(-10.0, 300.0)), MIN_SCALE)
+    }
+
+    #[test]
+    fn fit_keeps_the_aspect_ratio() {
+        let viewport = Viewport::default();
+
+        assert_eq!(viewport.scale(None, Size::new(640.0, 720.0)), 0.5);
+        assert_eq!(
+            viewport
+                .scale(Some(Size::new(400.0, 400.0)), Size::new(640.0, 200.0)),
+            0.5
+        )
+    }
+
+    #[test]
+    fn zoom_steps_through_levels() {
+        let viewport = Viewport::default().zoom_in();
+        assert_eq!(viewport.zoom, Zoom::Percent(125));
+
+        let viewport = viewport.zoom_out().zoom_out();
+        assert_eq!(viewport.zoom, Zoom::Percent(75));
+
+        let viewport = Viewport {
+            zoom: Zoom::Percent(25),
+            ..viewport
+        };
+        assert_eq!(viewport.zoom_out().zoom, Zoom::Percent(25))
+    }
+}
@@ -1,4 +1,5 @@
 mod alignment;
+mod color;
 mod content_fit;
 mod length;
 mod line_height;
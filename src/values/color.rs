@@ -0,0 +1,125 @@
+use std::num::ParseIntError;
+
+use iced::Color;
+
+use super::Value;
+
+#[derive(Debug, thiserror::Error, Clone, PartialEq)]
+pub enum ParseColorError {
+    #[error("int parsing error: {0}")]
+    ParseIntError(ParseIntError),
+    #[error("missing prefix")]
+    MissingPrefix,
+    #[error("invalid length")]
+    InvalidLength,
+    #[error("cannot parse color from empty string")]
+    Empty,
+}
+
+impl From<ParseIntError> for ParseColorError {
+    fn from(value: ParseIntError) -> Self {
+        Self::ParseIntError(value)
+    }
+}
+
+impl Value for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+
+        let Some(hex) = s.strip_prefix('#') else {
+            return Err(ParseColorError::MissingPrefix);
+        };
+
+        if !hex.is_ascii() {
+            return Err(ParseColorError::InvalidLength);
+        }
+
+        let channel =
+            |from: usize| u8::from_str_radix(&hex[from..from + 2], 16);
+
+        match hex.len() {
+            6 => Ok(Color::from_rgb8(channel(0)?, channel(2)?, channel(4)?)),
+            8 => Ok(Color::from_rgba8(
+                channel(0)?,
+                channel(2)?,
+                channel(4)?,
+                f32::from(channel(6)?) / 255.0,
+            )),
+            _ => Err(ParseColorError::InvalidLength),
+        }
+    }
+
+    fn to_string(&self) -> String {
+        let [r, g, b, a] = self.into_rgba8();
+
+        if a == u8::MAX {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_rgb_with_spaces() {
+        assert_eq!(
+            Color::from_str("  #ff8000 "),
+            Ok(Color::from_rgb8(255, 128, 0))
+        )
+    }
+
+    #[test]
+    fn can_parse_rgba() {
+        assert_eq!(
+            Color::from_str("#00000000"),
+            Ok(Color::from_rgba8(0, 0, 0, 0.0))
+        )
+    }
+
+    #[test]
+    fn can_round_trip() {
+        assert_eq!(
+            Color::from_str("#1E2a3b").map(|color| Value::to_string(&color)),
+            Ok(String::from("#1e2a3b"))
+        )
+    }
+
+    #[test]
+    fn cant_parse_with_missing_prefix() {
+        assert_eq!(
+            Color::from_str("ff8000"),
+            Err(ParseColorError::MissingPrefix)
+        )
+    }
+
+    #[test]
+    fn cant_parse_invalid_length() {
+        assert_eq!(Color::from_str("#fff"), Err(ParseColorError::InvalidLength))
+    }
+
+    #[test]
+    fn cant_parse_invalid_integer() {
+        assert_eq!(
+            Color::from_str("#gg0000"),
+            Err(ParseColorError::ParseIntError(
+                u8::from_str_radix("gg", 16)
+                    .expect_err("integer parse should fail")
+            ))
+        )
+    }
+
+    #[test]
+    fn cant_parse_empty_string() {
+        assert_eq!(Color::from_str(" "), Err(ParseColorError::Empty))
+    }
+}
@@ -1,9 +1,14 @@
 use iced::widget::{self, container, text, tooltip};
 use iced_material::Theme;
 
+pub use self::layout_debug::layout_debug;
+pub use self::scale::scale;
 use crate::types::Element;
 
 pub mod button;
+pub mod color;
+pub mod layout_debug;
+pub mod scale;
 pub mod tip {
     pub use super::tooltip::Position;
 }
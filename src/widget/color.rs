@@ -0,0 +1,49 @@
+use iced::widget::{Space, container, row, slider};
+use iced::{Color, Element};
+use iced_material::Theme;
+
+/// A square filled with `color`.
+pub fn swatch<'a, Message: 'a>(color: Color) -> Element<'a, Message, Theme> {
+    container(Space::new(24, 24))
+        .style(move |_: &Theme| container::Style {
+            background: Some(color.into()),
+            border: iced::border::rounded(4)
+                .width(1)
+                .color(Color::from_rgba(0.5, 0.5, 0.5, 0.5)),
+            ..container::Style::default()
+        })
+        .into()
+}
+
+/// Sliders for the red, green and blue channels of `color`, sending
+/// `on_release` when one is let go.
+pub fn sliders<'a, Message: Clone + 'a>(
+    color: Color,
+    on_change: impl Fn(Color) -> Message + Clone + 'a,
+    on_release: Option<Message>,
+) -> Element<'a, Message, Theme> {
+    let [r, g, b, _] = color.into_rgba8();
+    let channel = |channel: u8, set: fn(&mut [u8; 3], u8)| {
+        let on_change = on_change.clone();
+
+        let slider = slider(0..=255, channel, move |channel| {
+            let mut rgb = [r, g, b];
+            set(&mut rgb, channel);
+
+            on_change(Color::from_rgba8(rgb[0], rgb[1], rgb[2], color.a))
+        });
+
+        match on_release.clone() {
+            Some(on_release) => slider.on_release(on_release),
+            None => slider,
+        }
+    };
+
+    row![
+        channel(r, |rgb, value| rgb[0] = value),
+        channel(g, |rgb, value| rgb[1] = value),
+        channel(b, |rgb, value| rgb[2] = value),
+    ]
+    .spacing(10)
+    .into()
+}
@@ -0,0 +1,539 @@
+//! An overlay explaining the layout of the designed view.
+//!
+//! Every element of the view gets its bounds drawn, with its padding tinted
+//! as padding, the gaps between its children tinted as spacing and elements
+//! that collapsed to zero size marked. Elements are found through the ids of
+//! their widgets, so the widgets wrapping them are left out.
+use std::collections::HashMap;
+
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::{Id, Operation, Tree, Widget, tree};
+use iced::advanced::{Clipboard, Shell, overlay, renderer};
+use iced::{
+    Border, Color, Element, Event, Length, Padding, Point, Rectangle, Size,
+    Vector,
+};
+use iced::{mouse, touch};
+
+pub const BOUNDS: Color = Color::from_rgba(0.13, 0.59, 0.95, 0.8);
+pub const PADDING: Color = Color::from_rgba(0.3, 0.69, 0.31, 0.25);
+pub const SPACING: Color = Color::from_rgba(1.0, 0.6, 0.0, 0.3);
+pub const COLLAPSED: Color = Color::from_rgb(0.96, 0.26, 0.21);
+const HOVERED: Color = Color::from_rgb(0.13, 0.59, 0.95);
+
+/// Draws the layout of the `elements` in `content` on top of it, given the
+/// id of each element's widget and its padding.
+pub fn layout_debug<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    elements: HashMap<Id, Padding>,
+) -> LayoutDebug<'a, Message, Theme, Renderer> {
+    LayoutDebug {
+        content: content.into(),
+        elements,
+        scale: 1.0,
+        on_hover: None,
+    }
+}
+
+pub struct LayoutDebug<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    elements: HashMap<Id, Padding>,
+    scale: f32,
+    on_hover: Option<Box<dyn Fn(Option<Size>) -> Message + 'a>>,
+}
+
+impl<'a, Message, Theme, Renderer> LayoutDebug<'a, Message, Theme, Renderer> {
+    /// Sets the scale the content is drawn at, which padding is scaled by.
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the message sent with the size of the innermost element under
+    /// the cursor whenever it changes.
+    pub fn on_hover(
+        mut self,
+        on_hover: impl Fn(Option<Size>) -> Message + 'a,
+    ) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// The elements, relative to the content, parents before their children.
+    nodes: Vec<Node>,
+    hovered: Option<Rectangle>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Node {
+    bounds: Rectangle,
+    padding: Padding,
+    /// The index of the element it's a child of.
+    parent: Option<usize>,
+}
+
+/// Collects the nodes of the elements while operating on the content.
+struct Collect<'a> {
+    elements: &'a HashMap<Id, Padding>,
+    nodes: Vec<Node>,
+    parent: Option<usize>,
+    /// The element the widget about to be traversed belongs to.
+    last: Option<usize>,
+}
+
+impl Operation for Collect<'_> {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        let parent = self.parent;
+
+        if let Some(last) = self.last.take() {
+            self.parent = Some(last);
+        }
+
+        operate(self);
+
+        self.parent = parent;
+        self.last = None;
+    }
+
+    fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        self.last = None;
+
+        if let Some(&padding) = id.and_then(|id| self.elements.get(id)) {
+            self.nodes.push(Node {
+                bounds,
+                padding,
+                parent: self.parent,
+            });
+            self.last = Some(self.nodes.len() - 1);
+        }
+    }
+}
+
+/// The bounds of the innermost element containing `position`.
+fn innermost(nodes: &[Node], position: Point) -> Option<Rectangle> {
+    // Children come after their parents, and later ones are drawn on top
+    nodes
+        .iter()
+        .rev()
+        .map(|node| node.bounds)
+        .find(|bounds| bounds.contains(position))
+}
+
+/// The part of `bounds` inside of `padding`, drawn at `scale`.
+fn inset(bounds: Rectangle, padding: Padding, scale: f32) -> Rectangle {
+    let width = (bounds.width - padding.x() * scale).max(0.0);
+    let height = (bounds.height - padding.y() * scale).max(0.0);
+
+    Rectangle {
+        x: bounds.x + (padding.left * scale).min(bounds.width),
+        y: bounds.y + (padding.top * scale).min(bounds.height),
+        width,
+        height,
+    }
+}
+
+/// The gap between two consecutive children, if they are laid out next to
+/// or below each other, spanning the `content` they sit in.
+fn gap(
+    previous: Rectangle,
+    next: Rectangle,
+    content: Rectangle,
+) -> Option<Rectangle> {
+    let right = previous.x + previous.width;
+    let bottom = previous.y + previous.height;
+
+    if next.x > right {
+        Some(Rectangle {
+            x: right,
+            y: content.y,
+            width: next.x - right,
+            height: content.height,
+        })
+    } else if next.y > bottom {
+        Some(Rectangle {
+            x: content.x,
+            y: bottom,
+            width: content.width,
+            height: next.y - bottom,
+        })
+    } else {
+        None
+    }
+}
+
+/// The bands between `outer` and `inner`, clockwise from the top.
+fn bands(outer: Rectangle, inner: Rectangle) -> [Rectangle; 4] {
+    let inner_right = inner.x + inner.width;
+    let inner_bottom = inner.y + inner.height;
+
+    [
+        Rectangle {
+            height: inner.y - outer.y,
+            ..outer
+        },
+        Rectangle {
+            x: inner_right,
+            y: inner.y,
+            width: outer.x + outer.width - inner_right,
+            height: inner.height,
+        },
+        Rectangle {
+            y: inner_bottom,
+            height: outer.y + outer.height - inner_bottom,
+            ..outer
+        },
+        Rectangle {
+            x: outer.x,
+            y: inner.y,
+            width: inner.x - outer.x,
+            height: inner.height,
+        },
+    ]
+}
+
+/// Where the content is, as the nodes are relative to it.
+fn offset(layout: Layout<'_>) -> Vector {
+    layout.position() - Point::ORIGIN
+}
+
+fn fill<Renderer: iced::advanced::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+) {
+    if bounds.width > 0.0 && bounds.height > 0.0 {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+fn outline<Renderer: iced::advanced::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+    width: f32,
+) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                color,
+                width,
+                ..Border::default()
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+fn explain<Renderer: iced::advanced::Renderer>(
+    renderer: &mut Renderer,
+    nodes: &[Node],
+    offset: Vector,
+    scale: f32,
+) {
+    for (index, node) in nodes.iter().enumerate() {
+        let bounds = node.bounds + offset;
+
+        if bounds.width == 0.0 || bounds.height == 0.0 {
+            // Grown so that it can be seen
+            outline(renderer, bounds.expand(3.0), COLLAPSED, 2.0);
+        } else {
+            outline(renderer, bounds, BOUNDS, 1.0);
+        }
+
+        let content = inset(bounds, node.padding, scale);
+
+        for band in bands(bounds, content) {
+            fill(renderer, band, PADDING);
+        }
+
+        let children: Vec<Rectangle> = nodes
+            .iter()
+            .filter(|child| child.parent == Some(index))
+            .map(|child| child.bounds + offset)
+            .collect();
+
+        for pair in children.windows(2) {
+            if let Some(gap) = gap(pair[0], pair[1], content) {
+                fill(renderer, gap, SPACING);
+            }
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for LayoutDebug<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+
+        let mut collect = Collect {
+            elements: &self.elements,
+            nodes: Vec::new(),
+            parent: None,
+            last: None,
+        };
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            Layout::new(&node),
+            renderer,
+            &mut collect,
+        );
+        tree.state.downcast_mut::<State>().nodes = collect.nodes;
+
+        node
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. }) = event
+        {
+            let state = tree.state.downcast_mut::<State>();
+            let hovered = cursor.position().and_then(|position| {
+                innermost(&state.nodes, position - offset(layout))
+                    .map(|bounds| bounds + offset(layout))
+            });
+
+            if hovered != state.hovered {
+                state.hovered = hovered;
+                shell.request_redraw();
+
+                if let Some(on_hover) = &self.on_hover {
+                    shell
+                        .publish(on_hover(hovered.map(|bounds| bounds.size())));
+                }
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+
+        renderer.with_layer(*viewport, |renderer| {
+            explain(renderer, &state.nodes, offset(layout), self.scale);
+
+            if let Some(hovered) = state.hovered {
+                outline(renderer, hovered, HOVERED, 2.0);
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<LayoutDebug<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(layout_debug: LayoutDebug<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(layout_debug)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_gaps_between_children() {
+        let content = Rectangle::new(Point::ORIGIN, Size::new(100.0, 20.0));
+        let previous = Rectangle::new(Point::ORIGIN, Size::new(40.0, 20.0));
+        let next = Rectangle::new(Point::new(50.0, 0.0), Size::new(50.0, 20.0));
+
+        assert_eq!(
+            gap(previous, next, content),
+            Some(Rectangle::new(Point::new(40.0, 0.0), Size::new(10.0, 20.0)))
+        );
+        assert_eq!(gap(previous, previous, content), None)
+    }
+
+    #[test]
+    fn bands_surround_the_content() {
+        let outer = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let inner =
+            Rectangle::new(Point::new(20.0, 10.0), Size::new(60.0, 80.0));
+        let [top, right, bottom, left] = bands(outer, inner);
+
+        assert_eq!(top.height, 10.0);
+        assert_eq!(right.width, 20.0);
+        assert_eq!(bottom.y, 90.0);
+        assert_eq!(left.width, 20.0)
+    }
+
+    #[test]
+    fn collects_elements_under_their_parents() {
+        let parent = Id::unique();
+        let child = Id::unique();
+        let elements = HashMap::from([
+            (parent.clone(), Padding::new(5.0)),
+            (child.clone(), Padding::ZERO),
+        ]);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0));
+
+        let mut collect = Collect {
+            elements: &elements,
+            nodes: Vec::new(),
+            parent: None,
+            last: None,
+        };
+        collect.container(Some(&parent), bounds);
+        collect.traverse(&mut |operation| {
+            // A widget wrapping the child, which isn't an element
+            operation.container(None, bounds);
+            operation.traverse(&mut |operation| {
+                operation.container(Some(&child), bounds);
+            });
+        });
+
+        assert_eq!(collect.nodes.len(), 2);
+        assert_eq!(collect.nodes[0].parent, None);
+        assert_eq!(collect.nodes[0].padding, Padding::new(5.0));
+        assert_eq!(collect.nodes[1].parent, Some(0))
+    }
+
+    #[test]
+    fn insets_by_the_scaled_padding() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 50.0));
+
+        assert_eq!(
+            inset(bounds, Padding::new(10.0), 0.5),
+            Rectangle::new(Point::new(5.0, 5.0), Size::new(90.0, 40.0))
+        )
+    }
+}
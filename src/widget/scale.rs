@@ -0,0 +1,267 @@
+//! Draws its content scaled, after laying it out at its real size.
+//!
+//! The content is drawn through a transformation, while events, operations
+//! and overlays see a copy of its layout scaled the same way. Drop points
+//! and drop zones therefore stay in the coordinates of the window, just as
+//! they are for unscaled widgets.
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::{Operation, Tree, Widget, tree};
+use iced::advanced::{Clipboard, Shell, overlay, renderer};
+use iced::{
+    Element, Event, Length, Point, Rectangle, Size, Transformation, Vector,
+    mouse,
+};
+
+/// Lays out `content` in `size` and draws it at `scale`.
+pub fn scale<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    size: Size,
+    scale: f32,
+) -> Scale<'a, Message, Theme, Renderer> {
+    Scale {
+        content: content.into(),
+        size,
+        scale,
+    }
+}
+
+pub struct Scale<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    size: Size,
+    scale: f32,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// The layout of the content at its real size, which it's drawn with.
+    unscaled: layout::Node,
+}
+
+/// Scales `node` and its children, which are positioned relative to it.
+fn scaled(node: &layout::Node, scale: f32) -> layout::Node {
+    let bounds = node.bounds();
+
+    layout::Node::with_children(
+        bounds.size() * scale,
+        node.children()
+            .iter()
+            .map(|child| scaled(child, scale))
+            .collect(),
+    )
+    .move_to(Point::new(bounds.x * scale, bounds.y * scale))
+}
+
+impl<Message, Theme, Renderer> Scale<'_, Message, Theme, Renderer> {
+    /// Whether the content is drawn at a size it can be seen and used at.
+    fn is_visible(&self) -> bool {
+        self.scale > 0.0 && self.scale.is_finite()
+    }
+
+    /// Maps the unscaled content at `position` to where it's drawn.
+    fn transformation(&self, position: Point) -> Transformation {
+        Transformation::translate(position.x, position.y)
+            * Transformation::scale(self.scale)
+            * Transformation::translate(-position.x, -position.y)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Scale<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        let size = self.size * self.scale;
+
+        Size::new(Length::Fixed(size.width), Length::Fixed(size.height))
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        let unscaled = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(Size::ZERO, self.size),
+        );
+        let content = scaled(&unscaled, self.scale);
+
+        tree.state.downcast_mut::<State>().unscaled = unscaled;
+
+        layout::Node::with_children(self.size * self.scale, vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if !self.is_visible() {
+            return;
+        }
+
+        if let Some(content) = layout.children().next() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        // A zero scale can't be inverted to map the cursor back
+        if !self.is_visible() {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let position = layout.position();
+        let transformation = self.transformation(position);
+        let inverse = transformation.inverse();
+
+        renderer.with_transformation(transformation, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                Layout::with_offset(position - Point::ORIGIN, &state.unscaled),
+                cursor * inverse,
+                &(*viewport * inverse),
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout
+            .children()
+            .next()
+            .map_or(mouse::Interaction::None, |content| {
+                self.content.as_widget().mouse_interaction(
+                    &tree.children[0],
+                    content,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(content) = layout.children().next() {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                content,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Scale<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(scale: Scale<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_positions_and_sizes() {
+        let node = layout::Node::with_children(
+            Size::new(200.0, 100.0),
+            vec![
+                layout::Node::new(Size::new(50.0, 20.0))
+                    .move_to(Point::new(10.0, 40.0)),
+            ],
+        );
+
+        let node = scaled(&node, 0.5);
+        let child = &node.children()[0];
+
+        assert_eq!(node.size(), Size::new(100.0, 50.0));
+        assert_eq!(
+            child.bounds(),
+            Rectangle::new(Point::new(5.0, 20.0), Size::new(25.0, 10.0))
+        )
+    }
+}